@@ -10,8 +10,9 @@ use crate::error::{Error, Result};
 use crate::extractors::HierarchicalExtractor;
 use crate::geometry::Rect;
 use crate::object::{Object, ObjectRef};
+use crate::writer::pdf_string::{decode_text_string, escape_literal_bytes, hex_string, name_token};
 use crate::writer::{ContentStreamBuilder, ObjectSerializer};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::File;
 #[cfg(not(target_arch = "wasm32"))]
 use std::io::BufWriter;
@@ -37,6 +38,105 @@ pub struct DocumentInfo {
     pub creation_date: Option<String>,
     /// Modification date (PDF date format)
     pub mod_date: Option<String>,
+    /// Custom (non-standard) string entries, keyed by Info dictionary key
+    /// without the leading slash. Written in key order.
+    pub custom: BTreeMap<String, String>,
+}
+
+/// Standard `/Info` keys that map to dedicated [`DocumentInfo`] fields
+/// (ISO 32000-1:2008, Table 317). `Trapped` is a name, not a text string,
+/// so it is neither a field nor a custom entry.
+const STANDARD_INFO_KEYS: &[&str] = &[
+    "Title",
+    "Author",
+    "Subject",
+    "Keywords",
+    "Creator",
+    "Producer",
+    "CreationDate",
+    "ModDate",
+    "Trapped",
+];
+
+/// Content key for resource deduplication: the SHA-256 digest of the
+/// object's canonical serialization (dictionary keys are sorted).
+///
+/// A key hit replaces one resource with another without comparing bytes,
+/// so the digest has to be collision resistant. Keys only live in memory
+/// for the editor's lifetime and are never persisted.
+type ResourceKey = [u8; 32];
+
+fn resource_content_key(obj: &Object) -> ResourceKey {
+    use sha2::{Digest, Sha256};
+    let bytes = ObjectSerializer::compact().serialize(obj);
+    Sha256::digest(&bytes).into()
+}
+
+/// Whether an imported object is an immutable resource that pages can
+/// share: any stream (image and form XObjects, font programs, CMaps, ICC
+/// profiles) or a font / font-descriptor dictionary.
+fn is_shareable_resource(obj: &Object) -> bool {
+    match obj {
+        Object::Stream { .. } => true,
+        Object::Dictionary(dict) => matches!(
+            dict.get("Type"),
+            Some(Object::Name(t)) if t == "Font" || t == "FontDescriptor"
+        ),
+        _ => false,
+    }
+}
+
+/// Replace references to non-shareable objects in `local` with their
+/// content, so a resource's dedup key does not depend on the IDs freshly
+/// allocated to children (e.g. an indirect color space array) imported
+/// along with it. Shareable children keep their reference: they are
+/// deduplicated themselves, so their ID is already canonical.
+fn inline_references(
+    obj: &Object,
+    local: &HashMap<u32, &Object>,
+    visiting: &mut HashSet<u32>,
+) -> Object {
+    match obj {
+        Object::Reference(r) => match local.get(&r.id) {
+            Some(target) if !is_shareable_resource(target) && visiting.insert(r.id) => {
+                let inlined = inline_references(target, local, visiting);
+                visiting.remove(&r.id);
+                inlined
+            },
+            _ => obj.clone(),
+        },
+        Object::Array(items) => Object::Array(
+            items
+                .iter()
+                .map(|item| inline_references(item, local, visiting))
+                .collect(),
+        ),
+        Object::Dictionary(dict) => Object::Dictionary(
+            dict.iter()
+                .map(|(k, v)| (k.clone(), inline_references(v, local, visiting)))
+                .collect(),
+        ),
+        Object::Stream { dict, data } => Object::Stream {
+            dict: dict
+                .iter()
+                .map(|(k, v)| (k.clone(), inline_references(v, local, visiting)))
+                .collect(),
+            data: data.clone(),
+        },
+        _ => obj.clone(),
+    }
+}
+
+/// Whether `obj` contains an indirect reference to object `id`.
+fn references_id(obj: &Object, id: u32) -> bool {
+    match obj {
+        Object::Reference(r) => r.id == id,
+        Object::Array(items) => items.iter().any(|item| references_id(item, id)),
+        Object::Dictionary(dict) | Object::Stream { dict, .. } => {
+            dict.values().any(|value| references_id(value, id))
+        },
+        _ => false,
+    }
 }
 
 impl DocumentInfo {
@@ -81,36 +181,95 @@ impl DocumentInfo {
         self
     }
 
+    /// Set a custom Info entry (e.g. `"Company"`, `"SourceModified"`).
+    ///
+    /// Standard keys such as `"Title"` are routed to their dedicated field.
+    pub fn custom(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.set_entry(key, value);
+        self
+    }
+
+    /// Get an Info entry by key, standard or custom.
+    pub fn get_entry(&self, key: &str) -> Option<&str> {
+        match key {
+            "Title" => self.title.as_deref(),
+            "Author" => self.author.as_deref(),
+            "Subject" => self.subject.as_deref(),
+            "Keywords" => self.keywords.as_deref(),
+            "Creator" => self.creator.as_deref(),
+            "Producer" => self.producer.as_deref(),
+            "CreationDate" => self.creation_date.as_deref(),
+            "ModDate" => self.mod_date.as_deref(),
+            _ => self.custom.get(key).map(String::as_str),
+        }
+    }
+
+    /// Set an Info entry by key, standard or custom.
+    pub fn set_entry(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+        let value = value.into();
+        let field = match key.as_str() {
+            "Title" => &mut self.title,
+            "Author" => &mut self.author,
+            "Subject" => &mut self.subject,
+            "Keywords" => &mut self.keywords,
+            "Creator" => &mut self.creator,
+            "Producer" => &mut self.producer,
+            "CreationDate" => &mut self.creation_date,
+            "ModDate" => &mut self.mod_date,
+            _ => {
+                self.custom.insert(key, value);
+                return;
+            },
+        };
+        *field = Some(value);
+    }
+
+    /// Remove an Info entry by key. Returns the previous value, if any.
+    pub fn remove_entry(&mut self, key: &str) -> Option<String> {
+        match key {
+            "Title" => self.title.take(),
+            "Author" => self.author.take(),
+            "Subject" => self.subject.take(),
+            "Keywords" => self.keywords.take(),
+            "Creator" => self.creator.take(),
+            "Producer" => self.producer.take(),
+            "CreationDate" => self.creation_date.take(),
+            "ModDate" => self.mod_date.take(),
+            _ => self.custom.remove(key),
+        }
+    }
+
     /// Convert to a PDF Info dictionary object.
     pub fn to_object(&self) -> Object {
         let mut dict = HashMap::new();
 
         if let Some(ref title) = self.title {
-            dict.insert("Title".to_string(), Object::String(title.as_bytes().to_vec()));
+            dict.insert("Title".to_string(), Object::text_string(title));
         }
         if let Some(ref author) = self.author {
-            dict.insert("Author".to_string(), Object::String(author.as_bytes().to_vec()));
+            dict.insert("Author".to_string(), Object::text_string(author));
         }
         if let Some(ref subject) = self.subject {
-            dict.insert("Subject".to_string(), Object::String(subject.as_bytes().to_vec()));
+            dict.insert("Subject".to_string(), Object::text_string(subject));
         }
         if let Some(ref keywords) = self.keywords {
-            dict.insert("Keywords".to_string(), Object::String(keywords.as_bytes().to_vec()));
+            dict.insert("Keywords".to_string(), Object::text_string(keywords));
         }
         if let Some(ref creator) = self.creator {
-            dict.insert("Creator".to_string(), Object::String(creator.as_bytes().to_vec()));
+            dict.insert("Creator".to_string(), Object::text_string(creator));
         }
         if let Some(ref producer) = self.producer {
-            dict.insert("Producer".to_string(), Object::String(producer.as_bytes().to_vec()));
+            dict.insert("Producer".to_string(), Object::text_string(producer));
         }
         if let Some(ref creation_date) = self.creation_date {
-            dict.insert(
-                "CreationDate".to_string(),
-                Object::String(creation_date.as_bytes().to_vec()),
-            );
+            dict.insert("CreationDate".to_string(), Object::text_string(creation_date));
         }
         if let Some(ref mod_date) = self.mod_date {
-            dict.insert("ModDate".to_string(), Object::String(mod_date.as_bytes().to_vec()));
+            dict.insert("ModDate".to_string(), Object::text_string(mod_date));
+        }
+        for (key, value) in &self.custom {
+            dict.insert(key.clone(), Object::text_string(value));
         }
 
         Object::Dictionary(dict)
@@ -122,28 +281,36 @@ impl DocumentInfo {
 
         if let Some(dict) = obj.as_dict() {
             if let Some(Object::String(s)) = dict.get("Title") {
-                info.title = String::from_utf8_lossy(s).to_string().into();
+                info.title = Some(decode_text_string(s));
             }
             if let Some(Object::String(s)) = dict.get("Author") {
-                info.author = String::from_utf8_lossy(s).to_string().into();
+                info.author = Some(decode_text_string(s));
             }
             if let Some(Object::String(s)) = dict.get("Subject") {
-                info.subject = String::from_utf8_lossy(s).to_string().into();
+                info.subject = Some(decode_text_string(s));
             }
             if let Some(Object::String(s)) = dict.get("Keywords") {
-                info.keywords = String::from_utf8_lossy(s).to_string().into();
+                info.keywords = Some(decode_text_string(s));
             }
             if let Some(Object::String(s)) = dict.get("Creator") {
-                info.creator = String::from_utf8_lossy(s).to_string().into();
+                info.creator = Some(decode_text_string(s));
             }
             if let Some(Object::String(s)) = dict.get("Producer") {
-                info.producer = String::from_utf8_lossy(s).to_string().into();
+                info.producer = Some(decode_text_string(s));
             }
             if let Some(Object::String(s)) = dict.get("CreationDate") {
-                info.creation_date = String::from_utf8_lossy(s).to_string().into();
+                info.creation_date = Some(decode_text_string(s));
             }
             if let Some(Object::String(s)) = dict.get("ModDate") {
-                info.mod_date = String::from_utf8_lossy(s).to_string().into();
+                info.mod_date = Some(decode_text_string(s));
+            }
+            for (key, value) in dict {
+                if STANDARD_INFO_KEYS.contains(&key.as_str()) {
+                    continue;
+                }
+                if let Object::String(s) = value {
+                    info.custom.insert(key.clone(), decode_text_string(s));
+                }
             }
         }
 
@@ -179,6 +346,13 @@ pub struct SaveOptions {
     pub garbage_collect: bool,
     /// Encryption configuration (None = no encryption)
     pub encryption: Option<EncryptionConfig>,
+    /// Stamp `/Info.ModDate` with the current time on save. Off by default
+    /// so a save leaves the metadata byte-for-byte as configured.
+    ///
+    /// The trailer `/ID` follows [`DocumentEditor::set_file_id`]: the
+    /// source `/ID` is kept, except on an encrypted full rewrite without an
+    /// explicit ID, which gets a fresh one.
+    pub update_mod_date: bool,
 }
 
 impl SaveOptions {
@@ -455,6 +629,8 @@ pub struct DocumentEditor {
     next_object_id: u32,
     /// Modified metadata
     modified_info: Option<DocumentInfo>,
+    /// Explicit trailer `/ID` pair; `None` preserves the source document's ID
+    file_id: Option<(Vec<u8>, Vec<u8>)>,
     /// Page order (indices into original pages, or negative for removed)
     page_order: Vec<i32>,
     /// Number of pages in original document
@@ -497,6 +673,13 @@ pub struct DocumentEditor {
     /// Each entry contains a page object and all its dependent objects,
     /// with references remapped to new IDs in this document.
     merged_pages: Vec<MergedPageData>,
+    /// Content digest → object ID of resources (images, fonts, Form XObjects)
+    /// imported by merges, so identical resources are written only once.
+    imported_resources: HashMap<ResourceKey, u32>,
+    /// Resources first seen while importing the current page. Moved into
+    /// `imported_resources` only once the page lands in `merged_pages`, so a
+    /// failed import never leaves hashes pointing at unwritten objects.
+    staged_resources: HashMap<ResourceKey, u32>,
 }
 
 /// Data for a single page imported from another PDF during a merge operation.
@@ -590,6 +773,7 @@ impl DocumentEditor {
             new_objects: Vec::new(),
             next_object_id: next_id,
             modified_info: None,
+            file_id: None,
             page_order,
             original_page_count: page_count,
             is_modified: false,
@@ -610,6 +794,8 @@ impl DocumentEditor {
             deleted_form_fields: HashSet::new(),
             acroform_modified: false,
             merged_pages: Vec::new(),
+            imported_resources: HashMap::new(),
+            staged_resources: HashMap::new(),
         })
     }
 
@@ -626,6 +812,7 @@ impl DocumentEditor {
             new_objects: Vec::new(),
             next_object_id: next_id,
             modified_info: None,
+            file_id: None,
             page_order,
             original_page_count: page_count,
             is_modified: false,
@@ -646,6 +833,8 @@ impl DocumentEditor {
             deleted_form_fields: HashSet::new(),
             acroform_modified: false,
             merged_pages: Vec::new(),
+            imported_resources: HashMap::new(),
+            staged_resources: HashMap::new(),
         })
     }
 
@@ -666,6 +855,7 @@ impl DocumentEditor {
             new_objects: Vec::new(),
             next_object_id: next_id,
             modified_info: None,
+            file_id: None,
             page_order,
             original_page_count: page_count,
             is_modified: false,
@@ -686,6 +876,8 @@ impl DocumentEditor {
             deleted_form_fields: HashSet::new(),
             acroform_modified: false,
             merged_pages: Vec::new(),
+            imported_resources: HashMap::new(),
+            staged_resources: HashMap::new(),
         })
     }
 
@@ -711,6 +903,7 @@ impl DocumentEditor {
                 "Incremental saves are not supported for in-memory output".to_string(),
             ));
         }
+        self.apply_mod_date_policy(&options);
         let mut cursor = Cursor::new(Vec::new());
         self.write_full_to_writer(&mut cursor, &options)?;
         Ok(cursor.into_inner())
@@ -968,6 +1161,93 @@ impl DocumentEditor {
         self.is_modified = true;
     }
 
+    /// Get the raw PDF modification-date string.
+    pub fn mod_date(&mut self) -> Result<Option<String>> {
+        let info = self.get_info()?;
+        Ok(info.mod_date)
+    }
+
+    /// Set the raw PDF modification-date string. Persists to
+    /// `/Info.ModDate` on save, unless [`SaveOptions::update_mod_date`]
+    /// overwrites it with the save time.
+    pub fn set_mod_date(&mut self, date: impl Into<String>) {
+        let date = date.into();
+        if self.modified_info.is_none() {
+            self.modified_info = Some(self.get_info().unwrap_or_default());
+        }
+        if let Some(ref mut info) = self.modified_info {
+            info.mod_date = Some(date);
+        }
+        self.is_modified = true;
+    }
+
+    /// Get an `/Info` entry by key, standard (`"Title"`) or custom (`"Company"`).
+    pub fn info_entry(&mut self, key: &str) -> Result<Option<String>> {
+        let info = self.get_info()?;
+        Ok(info.get_entry(key).map(str::to_string))
+    }
+
+    /// Set an `/Info` entry by key. Standard keys update the matching
+    /// field; any other key is written as a custom text-string entry.
+    pub fn set_info_entry(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        if self.modified_info.is_none() {
+            self.modified_info = Some(self.get_info().unwrap_or_default());
+        }
+        if let Some(ref mut info) = self.modified_info {
+            info.set_entry(key, value);
+        }
+        self.is_modified = true;
+    }
+
+    /// Remove an `/Info` entry by key. Returns the previous value, if any.
+    pub fn remove_info_entry(&mut self, key: &str) -> Option<String> {
+        if self.modified_info.is_none() {
+            self.modified_info = Some(self.get_info().unwrap_or_default());
+        }
+        let removed = self
+            .modified_info
+            .as_mut()
+            .and_then(|info| info.remove_entry(key));
+        self.is_modified = true;
+        removed
+    }
+
+    /// Get the file identifier pair written to the trailer `/ID` on save.
+    ///
+    /// Returns the pair set via [`set_file_id`](Self::set_file_id), or the
+    /// source document's `/ID` if none was set.
+    pub fn file_id(&self) -> Option<(Vec<u8>, Vec<u8>)> {
+        if let Some(ref id) = self.file_id {
+            return Some(id.clone());
+        }
+        let ids = self.source.trailer().as_dict()?.get("ID")?.as_array()?;
+        match ids.as_slice() {
+            [Object::String(first), Object::String(second)] => {
+                Some((first.clone(), second.clone()))
+            },
+            _ => None,
+        }
+    }
+
+    /// Set the trailer `/ID` pair explicitly (ISO 32000-1:2008, §14.4).
+    ///
+    /// By default the source document's `/ID` is preserved on both full
+    /// rewrites and incremental updates. The exception is a full rewrite
+    /// with [`SaveOptions::encryption`] set: the encryption key is derived
+    /// from the first element, so that save generates a fresh `/ID` unless
+    /// one was set here.
+    pub fn set_file_id(&mut self, permanent: impl Into<Vec<u8>>, changing: impl Into<Vec<u8>>) {
+        self.file_id = Some((permanent.into(), changing.into()));
+        self.is_modified = true;
+    }
+
+    /// Stamp `/Info.ModDate` with the current time if the options ask for it.
+    fn apply_mod_date_policy(&mut self, options: &SaveOptions) {
+        if options.update_mod_date {
+            self.set_mod_date(chrono::Utc::now().format("D:%Y%m%d%H%M%SZ").to_string());
+        }
+    }
+
     // === Page operations ===
 
     /// Get the current page count (after modifications).
@@ -1140,10 +1420,13 @@ impl DocumentEditor {
             return Ok(0);
         }
 
-        // Import each page from the source document
+        // Import each page from the source document. One ID map for the
+        // whole source so resources shared between its pages are imported once.
+        let mut id_map = HashMap::new();
         for page_idx in 0..source_page_count {
-            let page_data = self.import_page_from_document(&mut source_doc, page_idx)?;
-            self.merged_pages.push(page_data);
+            let page_data =
+                self.import_page_from_document(&mut source_doc, page_idx, &mut id_map)?;
+            self.push_merged_page(page_data);
         }
 
         self.is_modified = true;
@@ -1154,10 +1437,14 @@ impl DocumentEditor {
     ///
     /// Performs a deep copy of the page object graph, remapping all indirect
     /// references to new object IDs allocated in this document.
+    ///
+    /// `id_map` maps source object IDs to IDs already imported from the same
+    /// source, so resources shared between its pages are imported once.
     fn import_page_from_document(
         &mut self,
         source: &mut PdfDocument,
         page_index: usize,
+        id_map: &mut HashMap<u32, u32>,
     ) -> Result<MergedPageData> {
         let page_ref = source.get_page_ref(page_index)?;
         let page_obj = source.load_object(page_ref)?;
@@ -1171,16 +1458,15 @@ impl DocumentEditor {
             page_obj
         };
 
-        // Map from source object ID -> new object ID in this document
-        let mut id_map: HashMap<u32, u32> = HashMap::new();
         // Collected objects: new_id -> remapped object
         let mut collected: Vec<(u32, Object)> = Vec::new();
+        self.staged_resources.clear();
 
         // Deep-copy the page object, recursively importing all referenced objects
         let final_page = self.deep_import_object(
             source,
             &stripped_page,
-            &mut id_map,
+            id_map,
             &mut collected,
             &mut HashSet::new(),
         )?;
@@ -1191,6 +1477,14 @@ impl DocumentEditor {
         })
     }
 
+    /// Add an imported page and register the resources it introduced for
+    /// deduplication by later imports.
+    fn push_merged_page(&mut self, page_data: MergedPageData) {
+        self.merged_pages.push(page_data);
+        self.imported_resources
+            .extend(self.staged_resources.drain());
+    }
+
     /// Recursively import a PDF object, remapping all indirect references.
     ///
     /// When an `Object::Reference` is encountered, the referenced object is
@@ -1226,11 +1520,55 @@ impl DocumentEditor {
 
                 // Load and recursively import the referenced object
                 let loaded = source.load_object(*obj_ref)?;
+                let first_child = collected.len();
                 let remapped =
                     self.deep_import_object(source, &loaded, id_map, collected, visiting)?;
 
                 visiting.remove(&obj_ref.id);
 
+                // Share a content-identical resource imported earlier (from
+                // this or another merged document) instead of copying it
+                // again. Children are deduplicated first, so identical fonts
+                // and XObjects from different sources hash the same. Skipped
+                // when something imported above already points at `new_id`.
+                if is_shareable_resource(&remapped) {
+                    let local: HashMap<u32, &Object> = collected[first_child..]
+                        .iter()
+                        .map(|(id, o)| (*id, o))
+                        .collect();
+                    let key = resource_content_key(&inline_references(
+                        &remapped,
+                        &local,
+                        &mut HashSet::new(),
+                    ));
+                    let known = self
+                        .imported_resources
+                        .get(&key)
+                        .or_else(|| self.staged_resources.get(&key))
+                        .copied();
+                    match known {
+                        Some(existing)
+                            if !references_id(&remapped, new_id)
+                                && !collected[first_child..]
+                                    .iter()
+                                    .any(|(_, o)| references_id(o, new_id)) =>
+                        {
+                            id_map.insert(obj_ref.id, existing);
+                            // Children imported only for this copy (e.g. an
+                            // ICCBased color space array) are now unreachable.
+                            let dropped: HashSet<u32> =
+                                collected.drain(first_child..).map(|(id, _)| id).collect();
+                            id_map.retain(|_, id| !dropped.contains(id));
+                            self.staged_resources.retain(|_, id| !dropped.contains(id));
+                            return Ok(Object::Reference(ObjectRef::new(existing, 0)));
+                        },
+                        Some(_) => {},
+                        None => {
+                            self.staged_resources.insert(key, new_id);
+                        },
+                    }
+                }
+
                 // Store the imported object
                 collected.push((new_id, remapped));
 
@@ -1310,9 +1648,11 @@ impl DocumentEditor {
             return Ok(0);
         }
 
+        let mut id_map = HashMap::new();
         for &page_idx in pages {
-            let page_data = self.import_page_from_document(&mut source_doc, page_idx)?;
-            self.merged_pages.push(page_data);
+            let page_data =
+                self.import_page_from_document(&mut source_doc, page_idx, &mut id_map)?;
+            self.push_merged_page(page_data);
         }
 
         self.is_modified = true;
@@ -1334,6 +1674,15 @@ impl DocumentEditor {
         self.modified_info.as_ref().map(|info| info.to_object())
     }
 
+    /// The source document's trailer `/Info` reference, if any.
+    fn source_info_ref(&self) -> Option<ObjectRef> {
+        self.source
+            .trailer()
+            .as_dict()
+            .and_then(|d| d.get("Info"))
+            .and_then(|i| i.as_reference())
+    }
+
     /// Write an incremental update to the PDF.
     #[cfg(not(target_arch = "wasm32"))]
     fn write_incremental(&mut self, path: impl AsRef<Path>) -> Result<()> {
@@ -1408,9 +1757,16 @@ impl DocumentEditor {
             }
         }
 
-        // Add /Info reference if we created one
+        // Add /Info reference: the new object if we created one, otherwise
+        // carry the original forward so the update's trailer stays complete.
         if self.modified_info.is_some() {
             write!(writer, "  /Info {} 0 R\n", self.next_object_id)?;
+        } else if let Some(info_ref) = self.source_info_ref() {
+            write!(writer, "  /Info {} {} R\n", info_ref.id, info_ref.gen)?;
+        }
+
+        if let Some((id1, id2)) = self.file_id() {
+            write!(writer, "  /ID [{} {}]\n", hex_string(&id1), hex_string(&id2))?;
         }
 
         write!(writer, ">>\n")?;
@@ -1672,11 +2028,16 @@ impl DocumentEditor {
         writer.write_all(b"%\x80\x81\x82\x83\n")?;
 
         let serializer = ObjectSerializer::compact();
+        // Content hash → image XObject written for a rebuilt page, shared
+        // by every later page that draws the same image.
+        let mut image_xobjects: HashMap<ResourceKey, ObjectRef> = HashMap::new();
 
         // Set up encryption if configured
         let (file_id, encrypt_dict, encryption_handler) =
             if let Some(config) = options.encryption.as_ref() {
-                let (id1, id2) = generate_file_id();
+                // An explicitly set /ID is honoured; otherwise encryption
+                // gets a fresh one, as the key is derived from it.
+                let (id1, id2) = self.file_id.clone().unwrap_or_else(generate_file_id);
 
                 // Convert EncryptionAlgorithm to encryption::Algorithm
                 let algorithm = match config.algorithm {
@@ -1706,7 +2067,7 @@ impl DocumentEditor {
 
                 (Some((id1, id2)), Some(encrypt_dict), Some(handler))
             } else {
-                (None, None, None)
+                (self.file_id(), None, None)
             };
 
         // Helper to serialize with or without encryption
@@ -1941,7 +2302,7 @@ impl DocumentEditor {
                 let mut sorted_refs = embedded_file_refs.clone();
                 sorted_refs.sort_by(|a, b| a.0.cmp(&b.0));
                 for (name, ref_) in sorted_refs {
-                    names_array.push(Object::String(name.as_bytes().to_vec()));
+                    names_array.push(Object::text_string(name));
                     names_array.push(Object::Reference(ref_));
                 }
 
@@ -2502,11 +2863,24 @@ impl DocumentEditor {
                                             let mut xobject_refs: Vec<(String, ObjectRef)> =
                                                 Vec::new();
                                             for pending_image in pending_images {
-                                                let xobj_id = self.allocate_object_id();
-
                                                 // Build XObject stream for the image
                                                 let xobj_stream =
                                                     Self::build_image_xobject(&pending_image.image);
+
+                                                // The same image on several pages (a
+                                                // signature, a logo) is written once.
+                                                let key = resource_content_key(&xobj_stream);
+                                                if let Some(&existing) = image_xobjects.get(&key) {
+                                                    xobject_refs.push((
+                                                        pending_image.resource_id,
+                                                        existing,
+                                                    ));
+                                                    continue;
+                                                }
+                                                let xobj_id = self.allocate_object_id();
+                                                image_xobjects
+                                                    .insert(key, ObjectRef::new(xobj_id, 0));
+
                                                 let offset = writer.stream_position()?;
                                                 let bytes = serialize_obj(
                                                     &serializer,
@@ -3475,6 +3849,12 @@ impl DocumentEditor {
         write!(writer, "  /Size {}\n", max_id + 1)?;
         write!(writer, "  /Root {} 0 R\n", catalog_ref.id)?;
 
+        // Unmodified metadata: keep pointing at the original Info object,
+        // provided the sweep above carried it over.
+        let info_ref = info_ref.or_else(|| {
+            self.source_info_ref()
+                .filter(|r| entry_map.get(&r.id).is_some_and(|(_, _, used)| *used))
+        });
         if let Some(info_ref) = info_ref {
             write!(writer, "  /Info {} {} R\n", info_ref.id, info_ref.gen)?;
         }
@@ -3484,11 +3864,10 @@ impl DocumentEditor {
             write!(writer, "  /Encrypt {} 0 R\n", enc_id)?;
         }
 
-        // Write file ID if encryption is enabled
+        // Write file ID (explicit, preserved from the source, or generated
+        // for encryption)
         if let Some((id1, id2)) = file_id {
-            let id1_hex: String = id1.iter().map(|b| format!("{:02X}", b)).collect();
-            let id2_hex: String = id2.iter().map(|b| format!("{:02X}", b)).collect();
-            write!(writer, "  /ID [<{}> <{}>]\n", id1_hex, id2_hex)?;
+            write!(writer, "  /ID [{} {}]\n", hex_string(&id1), hex_string(&id2))?;
         }
 
         write!(writer, ">>\n")?;
@@ -5282,7 +5661,7 @@ impl DocumentEditor {
                             .as_ref()
                             .and_then(|d| d.get("T"))
                             .and_then(|t| match t {
-                                Object::String(s) => String::from_utf8(s.clone()).ok(),
+                                Object::String(s) => Some(decode_text_string(s)),
                                 _ => None,
                             })
                             .unwrap_or_else(|| format!("widget@page{}", page));
@@ -6253,10 +6632,10 @@ impl DocumentEditor {
                 output.extend_from_slice(format!("{:.6} i\n", tolerance).as_bytes());
             },
             Operator::SetRenderingIntent { intent } => {
-                output.extend_from_slice(format!("/{} ri\n", intent).as_bytes());
+                output.extend_from_slice(format!("{} ri\n", name_token(intent)).as_bytes());
             },
             Operator::SetExtGState { dict_name } => {
-                output.extend_from_slice(format!("/{} gs\n", dict_name).as_bytes());
+                output.extend_from_slice(format!("{} gs\n", name_token(dict_name)).as_bytes());
             },
 
             // Path construction
@@ -6333,7 +6712,8 @@ impl DocumentEditor {
                 output.extend_from_slice(format!("{:.6} TL\n", leading).as_bytes());
             },
             Operator::Tf { font, size } => {
-                output.extend_from_slice(format!("/{} {:.6} Tf\n", font, size).as_bytes());
+                output
+                    .extend_from_slice(format!("{} {:.6} Tf\n", name_token(font), size).as_bytes());
             },
             Operator::Tr { render } => {
                 output.extend_from_slice(format!("{} Tr\n", render).as_bytes());
@@ -6360,15 +6740,7 @@ impl DocumentEditor {
             // Text showing
             Operator::Tj { text } => {
                 output.push(b'(');
-                for byte in text {
-                    match *byte {
-                        b'(' | b')' | b'\\' => {
-                            output.push(b'\\');
-                            output.push(*byte);
-                        },
-                        _ => output.push(*byte),
-                    }
-                }
+                output.extend_from_slice(&escape_literal_bytes(text));
                 output.extend_from_slice(b") Tj\n");
             },
             Operator::TJ { array } => {
@@ -6377,15 +6749,7 @@ impl DocumentEditor {
                     match item {
                         TextElement::String(text) => {
                             output.push(b'(');
-                            for byte in text {
-                                match *byte {
-                                    b'(' | b')' | b'\\' => {
-                                        output.push(b'\\');
-                                        output.push(*byte);
-                                    },
-                                    _ => output.push(*byte),
-                                }
-                            }
+                            output.extend_from_slice(&escape_literal_bytes(text));
                             output.push(b')');
                         },
                         TextElement::Offset(offset) => {
@@ -6397,15 +6761,7 @@ impl DocumentEditor {
             },
             Operator::Quote { text } => {
                 output.push(b'(');
-                for byte in text {
-                    match *byte {
-                        b'(' | b')' | b'\\' => {
-                            output.push(b'\\');
-                            output.push(*byte);
-                        },
-                        _ => output.push(*byte),
-                    }
-                }
+                output.extend_from_slice(&escape_literal_bytes(text));
                 output.extend_from_slice(b") '\n");
             },
             Operator::DoubleQuote {
@@ -6415,24 +6771,16 @@ impl DocumentEditor {
             } => {
                 output
                     .extend_from_slice(format!("{:.6} {:.6} (", word_space, char_space).as_bytes());
-                for byte in text {
-                    match *byte {
-                        b'(' | b')' | b'\\' => {
-                            output.push(b'\\');
-                            output.push(*byte);
-                        },
-                        _ => output.push(*byte),
-                    }
-                }
+                output.extend_from_slice(&escape_literal_bytes(text));
                 output.extend_from_slice(b") \"\n");
             },
 
             // Color space
             Operator::SetStrokeColorSpace { name } => {
-                output.extend_from_slice(format!("/{} CS\n", name).as_bytes());
+                output.extend_from_slice(format!("{} CS\n", name_token(name)).as_bytes());
             },
             Operator::SetFillColorSpace { name } => {
-                output.extend_from_slice(format!("/{} cs\n", name).as_bytes());
+                output.extend_from_slice(format!("{} cs\n", name_token(name)).as_bytes());
             },
             Operator::SetStrokeColor { components } => {
                 for c in components {
@@ -6451,7 +6799,7 @@ impl DocumentEditor {
                     output.extend_from_slice(format!("{:.6} ", c).as_bytes());
                 }
                 if let Some(p) = name {
-                    output.extend_from_slice(format!("/{} ", p).as_bytes());
+                    output.extend_from_slice(format!("{} ", name_token(p)).as_bytes());
                 }
                 output.extend_from_slice(b"SCN\n");
             },
@@ -6460,7 +6808,7 @@ impl DocumentEditor {
                     output.extend_from_slice(format!("{:.6} ", c).as_bytes());
                 }
                 if let Some(p) = name {
-                    output.extend_from_slice(format!("/{} ", p).as_bytes());
+                    output.extend_from_slice(format!("{} ", name_token(p)).as_bytes());
                 }
                 output.extend_from_slice(b"scn\n");
             },
@@ -6489,15 +6837,15 @@ impl DocumentEditor {
 
             // XObject
             Operator::Do { name } => {
-                output.extend_from_slice(format!("/{} Do\n", name).as_bytes());
+                output.extend_from_slice(format!("{} Do\n", name_token(name)).as_bytes());
             },
 
             // Marked content
             Operator::BeginMarkedContent { tag } => {
-                output.extend_from_slice(format!("/{} BMC\n", tag).as_bytes());
+                output.extend_from_slice(format!("{} BMC\n", name_token(tag)).as_bytes());
             },
             Operator::BeginMarkedContentDict { tag, properties } => {
-                output.extend_from_slice(format!("/{} ", tag).as_bytes());
+                output.extend_from_slice(format!("{} ", name_token(tag)).as_bytes());
                 self.serialize_object(output, properties);
                 output.extend_from_slice(b" BDC\n");
             },
@@ -6505,14 +6853,15 @@ impl DocumentEditor {
 
             // Shading
             Operator::PaintShading { name } => {
-                output.extend_from_slice(format!("/{} sh\n", name).as_bytes());
+                output.extend_from_slice(format!("{} sh\n", name_token(name)).as_bytes());
             },
 
             // Inline image (complex - serialize full BI...ID...EI sequence)
             Operator::InlineImage { dict, data } => {
                 output.extend_from_slice(b"BI\n");
                 for (key, value) in dict.iter() {
-                    output.extend_from_slice(format!("/{} ", key).as_bytes());
+                    output.extend_from_slice(name_token(key).as_bytes());
+                    output.push(b' ');
                     self.serialize_object(output, value);
                     output.push(b'\n');
                 }
@@ -6548,18 +6897,10 @@ impl DocumentEditor {
             },
             Object::Integer(i) => output.extend_from_slice(format!("{}", i).as_bytes()),
             Object::Real(r) => output.extend_from_slice(format!("{:.6}", r).as_bytes()),
-            Object::Name(n) => output.extend_from_slice(format!("/{}", n).as_bytes()),
+            Object::Name(n) => output.extend_from_slice(name_token(n).as_bytes()),
             Object::String(s) => {
                 output.push(b'(');
-                for byte in s {
-                    match *byte {
-                        b'(' | b')' | b'\\' => {
-                            output.push(b'\\');
-                            output.push(*byte);
-                        },
-                        _ => output.push(*byte),
-                    }
-                }
+                output.extend_from_slice(&escape_literal_bytes(s));
                 output.push(b')');
             },
             // Note: PDF HexStrings are stored as Object::String and serialized as literal strings
@@ -6576,7 +6917,8 @@ impl DocumentEditor {
             Object::Dictionary(dict) => {
                 output.extend_from_slice(b"<<");
                 for (key, value) in dict {
-                    output.extend_from_slice(format!("/{} ", key).as_bytes());
+                    output.extend_from_slice(name_token(key).as_bytes());
+                    output.push(b' ');
                     self.serialize_object(output, value);
                 }
                 output.extend_from_slice(b">>");
@@ -6754,6 +7096,7 @@ impl EditableDocument for DocumentEditor {
 
     #[cfg(not(target_arch = "wasm32"))]
     fn save_with_options(&mut self, path: impl AsRef<Path>, options: SaveOptions) -> Result<()> {
+        self.apply_mod_date_policy(&options);
         if options.incremental {
             self.write_incremental(path)
         } else {
@@ -6826,6 +7169,7 @@ impl DocumentEditor {
     /// Per PDF spec Section 8.9, images are represented as XObject streams.
     fn build_image_xobject(image: &crate::elements::ImageContent) -> Object {
         use crate::elements::{ColorSpace as ElemColorSpace, ImageFormat as ElemImageFormat};
+        use flate2::{write::ZlibEncoder, Compression};
 
         let mut dict = HashMap::new();
 
@@ -6867,11 +7211,24 @@ impl DocumentEditor {
             },
         }
 
-        dict.insert("Length".to_string(), Object::Integer(image.data.len() as i64));
+        // Raw samples (e.g. images read back out of a page) are stored
+        // uncompressed, so compress them to match the filter above.
+        let data = match image.format {
+            ElemImageFormat::Raw => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder
+                    .write_all(&image.data)
+                    .and_then(|_| encoder.finish())
+                    .unwrap_or_else(|_| image.data.clone())
+            },
+            _ => image.data.clone(),
+        };
+
+        dict.insert("Length".to_string(), Object::Integer(data.len() as i64));
 
         Object::Stream {
             dict,
-            data: image.data.clone().into(),
+            data: data.into(),
         }
     }
 }
@@ -6970,6 +7327,7 @@ mod tests {
             producer: Some("Producer".into()),
             creation_date: Some("D:20260101000000".into()),
             mod_date: Some("D:20260226000000".into()),
+            custom: BTreeMap::new(),
         };
 
         let obj = info.to_object();
@@ -7005,6 +7363,7 @@ mod tests {
             producer: Some("TestProducer".into()),
             creation_date: Some("D:20260101".into()),
             mod_date: Some("D:20260226".into()),
+            custom: BTreeMap::new(),
         };
 
         let obj = original.to_object();
@@ -7020,6 +7379,35 @@ mod tests {
         assert_eq!(original.mod_date, reconstructed.mod_date);
     }
 
+    #[test]
+    fn test_document_info_custom_entries_roundtrip() {
+        let original = DocumentInfo::new()
+            .title("T")
+            .custom("Company", "Acme")
+            .custom("Author", "routed");
+
+        assert_eq!(original.author.as_deref(), Some("routed"));
+        assert_eq!(original.custom.len(), 1);
+
+        let mut dict = original.to_object().as_dict().unwrap().clone();
+        dict.insert("Trapped".to_string(), Object::Name("False".to_string()));
+        let reconstructed = DocumentInfo::from_object(&Object::Dictionary(dict));
+
+        assert_eq!(reconstructed.get_entry("Company"), Some("Acme"));
+        assert_eq!(reconstructed.get_entry("Title"), Some("T"));
+        assert!(!reconstructed.custom.contains_key("Trapped"));
+        assert!(!reconstructed.custom.contains_key("Title"));
+    }
+
+    #[test]
+    fn test_document_info_remove_entry() {
+        let mut info = DocumentInfo::new().title("T").custom("Draft", "yes");
+        assert_eq!(info.remove_entry("Draft").as_deref(), Some("yes"));
+        assert_eq!(info.remove_entry("Title").as_deref(), Some("T"));
+        assert_eq!(info.remove_entry("Missing"), None);
+        assert!(info.to_object().as_dict().unwrap().is_empty());
+    }
+
     #[test]
     fn test_document_info_from_non_dict() {
         // from_object on a non-dict should return all None
@@ -8195,6 +8583,22 @@ mod tests {
         assert_eq!(&output, b"/Type");
     }
 
+    #[test]
+    fn test_serialize_operator_name_round_trip() {
+        use crate::content::parser::parse_content_stream;
+
+        let editor = create_test_editor();
+        let source = b"/F#C3#A9 12 Tf /GS#20a gs /Im#28x#29 Do\n";
+        let mut output = Vec::new();
+        for op in parse_content_stream(source).unwrap() {
+            editor.serialize_operator(&mut output, &op);
+        }
+        let text = String::from_utf8(output).unwrap();
+        assert!(text.contains("/F#C3#A9 12.000000 Tf"), "{}", text);
+        assert!(text.contains("/GS#20a gs"), "{}", text);
+        assert!(text.contains("/Im#28x#29 Do"), "{}", text);
+    }
+
     #[test]
     fn test_serialize_object_string() {
         let editor = create_test_editor();
@@ -8493,8 +8897,15 @@ mod tests {
             data: stream_data,
         } = obj
         {
-            assert_eq!(dict.get("Length").unwrap().as_integer().unwrap(), 5);
-            assert_eq!(stream_data.as_ref(), &data[..]);
+            use flate2::read::ZlibDecoder;
+
+            // Raw samples are Flate-compressed to match the /Filter.
+            assert_eq!(dict.get("Length").unwrap().as_integer().unwrap(), stream_data.len() as i64);
+            let mut inflated = Vec::new();
+            ZlibDecoder::new(stream_data.as_ref())
+                .read_to_end(&mut inflated)
+                .unwrap();
+            assert_eq!(inflated, data);
         } else {
             panic!("Expected Stream object");
         }
@@ -9762,6 +10173,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_abandoned_page_import_does_not_register_resources() {
+        let source_bytes = crate::api::Pdf::from_text("Shared").unwrap().into_bytes();
+        let mut editor = create_test_editor();
+
+        // A page whose import fails part-way is dropped without being pushed.
+        let mut source = PdfDocument::from_bytes(source_bytes.clone()).unwrap();
+        let abandoned = editor
+            .import_page_from_document(&mut source, 0, &mut HashMap::new())
+            .unwrap();
+        assert!(!abandoned.objects.is_empty());
+        assert!(editor.imported_resources.is_empty());
+
+        // The next import must copy the resources again, not point at the
+        // objects of the abandoned page.
+        let mut source = PdfDocument::from_bytes(source_bytes).unwrap();
+        let page = editor
+            .import_page_from_document(&mut source, 0, &mut HashMap::new())
+            .unwrap();
+        assert_eq!(page.objects.len(), abandoned.objects.len());
+
+        editor.push_merged_page(page);
+        assert!(!editor.imported_resources.is_empty());
+    }
+
     #[test]
     fn test_merge_multiple_pages() {
         // Create PDFs with different content
@@ -9828,4 +10264,47 @@ mod tests {
         assert!(!text_page0.contains("Hello from B"), "Page 0 should NOT contain text from B");
         assert!(!text_page1.contains("Hello from A"), "Page 1 should NOT contain text from A");
     }
+
+    #[test]
+    fn test_resource_content_key_ignores_key_order() {
+        let mut a = HashMap::new();
+        a.insert("Type".to_string(), Object::Name("Font".to_string()));
+        a.insert("BaseFont".to_string(), Object::Name("Helvetica".to_string()));
+        let mut b = HashMap::new();
+        b.insert("BaseFont".to_string(), Object::Name("Helvetica".to_string()));
+        b.insert("Type".to_string(), Object::Name("Font".to_string()));
+
+        let key_a = resource_content_key(&Object::Dictionary(a.clone()));
+        assert_eq!(key_a, resource_content_key(&Object::Dictionary(b)));
+
+        a.insert("BaseFont".to_string(), Object::Name("Courier".to_string()));
+        assert_ne!(key_a, resource_content_key(&Object::Dictionary(a)));
+    }
+
+    #[test]
+    fn test_shareable_resources() {
+        let mut font = HashMap::new();
+        font.insert("Type".to_string(), Object::Name("Font".to_string()));
+        assert!(is_shareable_resource(&Object::Dictionary(font)));
+
+        let mut page = HashMap::new();
+        page.insert("Type".to_string(), Object::Name("Page".to_string()));
+        assert!(!is_shareable_resource(&Object::Dictionary(page)));
+
+        let stream = Object::Stream {
+            dict: HashMap::new(),
+            data: bytes::Bytes::from_static(b"q Q"),
+        };
+        assert!(is_shareable_resource(&stream));
+        assert!(!is_shareable_resource(&Object::Integer(1)));
+    }
+
+    #[test]
+    fn test_references_id_is_recursive() {
+        let mut inner = HashMap::new();
+        inner.insert("Parent".to_string(), Object::Reference(ObjectRef::new(7, 0)));
+        let obj = Object::Array(vec![Object::Integer(1), Object::Dictionary(inner)]);
+        assert!(references_id(&obj, 7));
+        assert!(!references_id(&obj, 8));
+    }
 }
@@ -1022,6 +1022,23 @@ impl PdfPage {
         crate::rendering::render_page(doc, self.page_index, options)
     }
 
+    /// Export this page as a standalone SVG document.
+    ///
+    /// Text, paths, tables and images are converted to SVG elements in
+    /// page coordinates. Fonts are mapped to web-safe CSS font stacks and
+    /// images are embedded as base64 data URIs; formats browsers cannot
+    /// display (JPEG 2000, JBIG2) are omitted.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let page = editor.get_page(0)?;
+    /// std::fs::write("page.svg", page.to_svg())?;
+    /// ```
+    pub fn to_svg(&self) -> String {
+        super::svg::page_to_svg(self)
+    }
+
     /// Get the root element as a PdfElement.
     pub fn root(&self) -> PdfElement {
         let id = ElementId::new();
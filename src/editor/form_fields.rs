@@ -122,7 +122,7 @@ impl From<&FieldValue> for FormFieldValue {
 impl From<&FormFieldValue> for Object {
     fn from(value: &FormFieldValue) -> Self {
         match value {
-            FormFieldValue::Text(s) => Object::String(s.as_bytes().to_vec()),
+            FormFieldValue::Text(s) => Object::text_string(s),
             FormFieldValue::Boolean(b) => {
                 // Checkboxes use /Yes or /Off names
                 if *b {
@@ -131,12 +131,10 @@ impl From<&FormFieldValue> for Object {
                     Object::Name("Off".to_string())
                 }
             },
-            FormFieldValue::Choice(s) => Object::String(s.as_bytes().to_vec()),
-            FormFieldValue::MultiChoice(v) => Object::Array(
-                v.iter()
-                    .map(|s| Object::String(s.as_bytes().to_vec()))
-                    .collect(),
-            ),
+            FormFieldValue::Choice(s) => Object::text_string(s),
+            FormFieldValue::MultiChoice(v) => {
+                Object::Array(v.iter().map(Object::text_string).collect())
+            },
             FormFieldValue::None => Object::Null,
         }
     }
@@ -640,7 +638,7 @@ impl FormFieldWrapper {
         if let Some(parent_ref) = self.parent_ref {
             dict.insert("Parent".to_string(), Object::Reference(parent_ref));
             // Use partial name instead of full name for child fields
-            dict.insert("T".to_string(), Object::String(self.partial_name.as_bytes().to_vec()));
+            dict.insert("T".to_string(), Object::text_string(&self.partial_name));
         }
 
         dict
@@ -696,7 +694,7 @@ impl FormFieldWrapper {
         let mut dict = HashMap::new();
 
         // Partial name (T) - required
-        dict.insert("T".to_string(), Object::String(self.partial_name.as_bytes().to_vec()));
+        dict.insert("T".to_string(), Object::text_string(&self.partial_name));
 
         // Field type (FT) - optional for non-terminal, but useful for inheritance
         if let Some(ref ft) = self.field_type {
@@ -740,7 +738,7 @@ impl FormFieldWrapper {
 
         // Tooltip
         if let Some(ref tooltip) = self.modified_tooltip {
-            dict.insert("TU".to_string(), Object::String(tooltip.as_bytes().to_vec()));
+            dict.insert("TU".to_string(), Object::text_string(tooltip));
         }
 
         dict
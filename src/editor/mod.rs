@@ -89,6 +89,7 @@ mod document_editor;
 pub mod dom;
 pub mod form_fields;
 pub mod resource_manager;
+mod svg;
 
 pub use document_editor::{
     DocumentEditor, DocumentInfo, EditableDocument, EncryptionAlgorithm, EncryptionConfig,
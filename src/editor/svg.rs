@@ -0,0 +1,674 @@
+//! SVG export for DOM pages.
+//!
+//! Converts the vector, text and image content of a [`PdfPage`] into a
+//! standalone SVG document, suitable for HTML previews without going
+//! through a rasterizer.
+//!
+//! Everything is emitted inside a single group that flips the y axis, so
+//! element coordinates stay in PDF user space (origin bottom-left) and the
+//! geometry matches what [`ContentStreamBuilder`] writes for the same
+//! elements. Text and images are flipped back locally so they read upright.
+//!
+//! Fonts are not embedded: font names are mapped onto web-safe CSS font
+//! stacks (see [`web_safe_font_family`]). Images are embedded as base64
+//! data URIs when the browser can decode them (JPEG, PNG and 8-bit
+//! Gray/RGB raw samples).
+//!
+//! [`ContentStreamBuilder`]: crate::writer::ContentStreamBuilder
+//!
+//! # Example
+//!
+//! ```ignore
+//! use pdf_oxide::editor::DocumentEditor;
+//!
+//! let mut editor = DocumentEditor::open("document.pdf")?;
+//! let page = editor.get_page(0)?;
+//! std::fs::write("page0.svg", page.to_svg())?;
+//! ```
+
+use super::dom::PdfPage;
+use crate::elements::{
+    ColorSpace, ContentElement, ImageContent, ImageFormat, LineCap, LineJoin, PathContent,
+    PathOperation, StructureElement, TableCellAlign, TableContent, TextContent,
+};
+use crate::layout::Color;
+use std::fmt::Write;
+
+/// Convert a page to a complete SVG document.
+pub(crate) fn page_to_svg(page: &PdfPage) -> String {
+    let mut out = String::new();
+    let w = fmt_num(page.width);
+    let h = fmt_num(page.height);
+
+    let _ = write!(
+        out,
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <svg xmlns=\"http://www.w3.org/2000/svg\" \
+         xmlns:xlink=\"http://www.w3.org/1999/xlink\" version=\"1.1\" \
+         viewBox=\"0 0 {w} {h}\" width=\"{w}pt\" height=\"{h}pt\">\n"
+    );
+    let _ = writeln!(out, "<rect width=\"{w}\" height=\"{h}\" fill=\"white\"/>");
+    let _ = writeln!(out, "<g transform=\"matrix(1 0 0 -1 0 {h})\">");
+    write_children(&mut out, &page.root.children);
+    out.push_str("</g>\n</svg>\n");
+    out
+}
+
+fn write_children(out: &mut String, children: &[ContentElement]) {
+    for child in children {
+        match child {
+            ContentElement::Text(t) => write_text(out, t),
+            ContentElement::Image(i) => write_image(out, i),
+            ContentElement::Path(p) => write_path(out, p),
+            ContentElement::Table(t) => write_table(out, t),
+            ContentElement::Structure(s) => write_structure(out, s),
+        }
+    }
+}
+
+/// Structure elements become groups tagged with their structure type so
+/// the hierarchy survives in the SVG DOM.
+fn write_structure(out: &mut String, s: &StructureElement) {
+    let _ = write!(out, "<g data-structure-type=\"{}\"", escape_xml(&s.structure_type));
+    if let Some(lang) = &s.language {
+        let _ = write!(out, " xml:lang=\"{}\"", escape_xml(lang));
+    }
+    out.push_str(">\n");
+    if let Some(alt) = &s.alt_text {
+        let _ = writeln!(out, "<title>{}</title>", escape_xml(alt));
+    }
+    write_children(out, &s.children);
+    out.push_str("</g>\n");
+}
+
+fn write_text(out: &mut String, text: &TextContent) {
+    if text.text.is_empty() {
+        return;
+    }
+
+    // Baseline origin: ContentStreamBuilder places text at (bbox.x, bbox.y)
+    // unless an explicit origin was recorded during extraction.
+    let (x, y) = match text.origin {
+        Some(p) => (p.x, p.y),
+        None => (text.bbox.x, text.bbox.y),
+    };
+
+    // Undo the outer y-flip so glyphs render upright, then apply rotation
+    // (counter-clockwise in PDF space, as for the text matrix).
+    let transform = match text.rotation_degrees {
+        Some(deg) if deg.abs() > f32::EPSILON => {
+            format!("translate({} {}) rotate({}) scale(1 -1)", fmt_num(x), fmt_num(y), fmt_num(deg))
+        },
+        _ => format!("matrix(1 0 0 -1 {} {})", fmt_num(x), fmt_num(y)),
+    };
+
+    let _ = write!(
+        out,
+        "<text transform=\"{}\" font-family=\"{}\" font-size=\"{}\" fill=\"{}\"",
+        transform,
+        web_safe_font_family(&text.font.name),
+        fmt_num(text.font.size),
+        rgb(text.style.color)
+    );
+    if text.style.weight.is_bold() || font_name_implies_bold(&text.font.name) {
+        let _ = write!(out, " font-weight=\"{}\"", (text.style.weight as u16).max(700));
+    }
+    if text.style.italic || font_name_implies_italic(&text.font.name) {
+        out.push_str(" font-style=\"italic\"");
+    }
+    match (text.style.underline, text.style.strikethrough) {
+        (true, true) => out.push_str(" text-decoration=\"underline line-through\""),
+        (true, false) => out.push_str(" text-decoration=\"underline\""),
+        (false, true) => out.push_str(" text-decoration=\"line-through\""),
+        (false, false) => {},
+    }
+    let _ = writeln!(out, " xml:space=\"preserve\">{}</text>", escape_xml(&text.text));
+}
+
+fn write_path(out: &mut String, path: &PathContent) {
+    let d = path_data(&path.operations);
+    if d.is_empty() {
+        return;
+    }
+
+    if let Some(m) = path.matrix {
+        let _ = writeln!(out, "<g transform=\"{}\">", svg_matrix(m));
+    }
+
+    let _ = write!(out, "<path d=\"{}\"", d);
+    match path.fill_color {
+        Some(c) => {
+            let _ = write!(out, " fill=\"{}\"", rgb(c));
+        },
+        None => out.push_str(" fill=\"none\""),
+    }
+    match path.stroke_color {
+        Some(c) => {
+            let _ = write!(
+                out,
+                " stroke=\"{}\" stroke-width=\"{}\"",
+                rgb(c),
+                fmt_num(path.stroke_width)
+            );
+            match path.line_cap {
+                LineCap::Butt => {},
+                LineCap::Round => out.push_str(" stroke-linecap=\"round\""),
+                LineCap::Square => out.push_str(" stroke-linecap=\"square\""),
+            }
+            match path.line_join {
+                LineJoin::Miter => {},
+                LineJoin::Round => out.push_str(" stroke-linejoin=\"round\""),
+                LineJoin::Bevel => out.push_str(" stroke-linejoin=\"bevel\""),
+            }
+            if let Some((dashes, phase)) = &path.dash_pattern {
+                if !dashes.is_empty() {
+                    let list: Vec<String> = dashes.iter().map(|d| fmt_num(*d)).collect();
+                    let _ = write!(out, " stroke-dasharray=\"{}\"", list.join(" "));
+                    if *phase != 0.0 {
+                        let _ = write!(out, " stroke-dashoffset=\"{}\"", fmt_num(*phase));
+                    }
+                }
+            }
+        },
+        None => out.push_str(" stroke=\"none\""),
+    }
+    out.push_str("/>\n");
+
+    if path.matrix.is_some() {
+        out.push_str("</g>\n");
+    }
+}
+
+fn path_data(operations: &[PathOperation]) -> String {
+    let mut d = String::new();
+    for op in operations {
+        match *op {
+            PathOperation::MoveTo(x, y) => {
+                let _ = write!(d, "M{} {} ", fmt_num(x), fmt_num(y));
+            },
+            PathOperation::LineTo(x, y) => {
+                let _ = write!(d, "L{} {} ", fmt_num(x), fmt_num(y));
+            },
+            PathOperation::CurveTo(x1, y1, x2, y2, x3, y3) => {
+                let _ = write!(
+                    d,
+                    "C{} {} {} {} {} {} ",
+                    fmt_num(x1),
+                    fmt_num(y1),
+                    fmt_num(x2),
+                    fmt_num(y2),
+                    fmt_num(x3),
+                    fmt_num(y3)
+                );
+            },
+            PathOperation::Rectangle(x, y, w, h) => {
+                let _ = write!(
+                    d,
+                    "M{} {} h{} v{} h{} Z ",
+                    fmt_num(x),
+                    fmt_num(y),
+                    fmt_num(w),
+                    fmt_num(h),
+                    fmt_num(-w)
+                );
+            },
+            PathOperation::ClosePath => d.push_str("Z "),
+        }
+    }
+    d.truncate(d.trim_end().len());
+    d
+}
+
+fn write_image(out: &mut String, image: &ImageContent) {
+    let Some(uri) = image_data_uri(image) else {
+        let _ = writeln!(
+            out,
+            "<!-- image omitted: {} is not displayable in SVG -->",
+            image.format.mime_type()
+        );
+        return;
+    };
+
+    if let Some(m) = image.matrix {
+        let _ = writeln!(out, "<g transform=\"{}\">", svg_matrix(m));
+    }
+
+    // Map the unit square onto the bbox exactly like the `cm` emitted by
+    // ContentStreamBuilder::draw_image, flipping rows back to top-down.
+    // SVG 1.1 only understands the namespaced `xlink:href`.
+    let b = image.bbox;
+    let _ = write!(
+        out,
+        "<image transform=\"matrix({} 0 0 {} {} {})\" width=\"1\" height=\"1\" \
+         preserveAspectRatio=\"none\" xlink:href=\"{}\"",
+        fmt_num(b.width),
+        fmt_num(-b.height),
+        fmt_num(b.x),
+        fmt_num(b.y + b.height),
+        uri
+    );
+    if let Some(alt) = &image.alt_text {
+        let _ = write!(out, " aria-label=\"{}\"", escape_xml(alt));
+    }
+    out.push_str("/>\n");
+
+    if image.matrix.is_some() {
+        out.push_str("</g>\n");
+    }
+}
+
+/// Encode an image as a data URI, or `None` if browsers cannot display it.
+fn image_data_uri(image: &ImageContent) -> Option<String> {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    let (mime, bytes) = match image.format {
+        ImageFormat::Jpeg => ("image/jpeg", std::borrow::Cow::Borrowed(&image.data[..])),
+        ImageFormat::Png => ("image/png", std::borrow::Cow::Borrowed(&image.data[..])),
+        ImageFormat::Raw => ("image/png", std::borrow::Cow::Owned(raw_to_png(image)?)),
+        ImageFormat::Jpeg2000 | ImageFormat::Jbig2 | ImageFormat::Unknown => return None,
+    };
+    if bytes.is_empty() {
+        return None;
+    }
+    Some(format!("data:{};base64,{}", mime, STANDARD.encode(&bytes)))
+}
+
+/// Re-encode 8-bit Gray/RGB raw samples as PNG.
+fn raw_to_png(image: &ImageContent) -> Option<Vec<u8>> {
+    use image::codecs::png::PngEncoder;
+    use image::ImageEncoder;
+
+    if image.bits_per_component != 8 {
+        return None;
+    }
+    let pixels = image.width as usize * image.height as usize;
+    let color_type = match image.color_space {
+        ColorSpace::Gray if image.data.len() == pixels => image::ColorType::L8,
+        ColorSpace::RGB if image.data.len() == pixels * 3 => image::ColorType::Rgb8,
+        _ => return None,
+    };
+
+    let mut buffer = Vec::new();
+    PngEncoder::new(&mut buffer)
+        .write_image(&image.data, image.width, image.height, color_type.into())
+        .ok()?;
+    Some(buffer)
+}
+
+/// Tables are laid out with the same geometry as
+/// `ContentStreamBuilder::add_table_content`.
+fn write_table(out: &mut String, table: &TableContent) {
+    let style = &table.style;
+    let padding = style.cell_padding;
+    let bbox = table.bbox;
+    let row_count = table.rows.len().max(1) as f32;
+
+    out.push_str("<g data-structure-type=\"Table\">\n");
+
+    let mut current_y = bbox.y + bbox.height;
+    for (row_idx, row) in table.rows.iter().enumerate() {
+        let row_height = row.height.unwrap_or(bbox.height / row_count);
+        current_y -= row_height;
+
+        let row_fill = row
+            .background
+            .or(if row.is_header {
+                style.header_background
+            } else {
+                None
+            })
+            .or(if row_idx % 2 == 1 {
+                style.stripe_background
+            } else {
+                None
+            });
+        if let Some(fill) = row_fill {
+            write_rect(out, bbox.x, current_y, bbox.width, row_height, fill);
+        }
+
+        let mut current_x = bbox.x;
+        for (col_idx, cell) in row.cells.iter().enumerate() {
+            let cell_width = if col_idx < table.column_widths.len() {
+                table.column_widths[col_idx] * cell.colspan as f32
+            } else if !table.column_widths.is_empty() {
+                table.column_widths[0]
+            } else {
+                bbox.width / row.cells.len() as f32
+            };
+
+            if let Some(fill) = cell.background {
+                write_rect(out, current_x, current_y, cell_width, row_height, fill);
+            }
+
+            if !cell.text.is_empty() {
+                let font_size = cell.font_size.unwrap_or(10.0);
+                let (text_x, anchor) = match cell.align {
+                    TableCellAlign::Left => (current_x + padding, "start"),
+                    TableCellAlign::Center => (current_x + cell_width / 2.0, "middle"),
+                    TableCellAlign::Right => (current_x + cell_width - padding, "end"),
+                };
+                let text_y = current_y + row_height - padding - font_size;
+                let family = cell.font_name.as_deref().unwrap_or("Helvetica");
+
+                let _ = write!(
+                    out,
+                    "<text transform=\"matrix(1 0 0 -1 {} {})\" font-family=\"{}\" \
+                     font-size=\"{}\" fill=\"black\"",
+                    fmt_num(text_x),
+                    fmt_num(text_y),
+                    web_safe_font_family(family),
+                    fmt_num(font_size)
+                );
+                if anchor != "start" {
+                    let _ = write!(out, " text-anchor=\"{}\"", anchor);
+                }
+                if cell.bold || cell.is_header {
+                    out.push_str(" font-weight=\"700\"");
+                }
+                if cell.italic {
+                    out.push_str(" font-style=\"italic\"");
+                }
+                let _ = writeln!(out, " xml:space=\"preserve\">{}</text>", escape_xml(&cell.text));
+            }
+
+            current_x += cell_width;
+        }
+    }
+
+    if style.border_width > 0.0 {
+        let mut d = String::new();
+        if style.outer_border {
+            d.push_str(&path_data(&[PathOperation::Rectangle(
+                bbox.x,
+                bbox.y,
+                bbox.width,
+                bbox.height,
+            )]));
+            d.push(' ');
+        }
+        if style.horizontal_borders {
+            let mut y = bbox.y + bbox.height;
+            for row in &table.rows {
+                y -= row.height.unwrap_or(bbox.height / row_count);
+                if y > bbox.y {
+                    let _ = write!(
+                        d,
+                        "M{} {} H{} ",
+                        fmt_num(bbox.x),
+                        fmt_num(y),
+                        fmt_num(bbox.x + bbox.width)
+                    );
+                }
+            }
+        }
+        if style.vertical_borders && !table.column_widths.is_empty() {
+            let mut x = bbox.x;
+            let last = table.column_widths.len() - 1;
+            for (i, &width) in table.column_widths.iter().enumerate() {
+                x += width;
+                if i < last {
+                    let _ = write!(
+                        d,
+                        "M{} {} V{} ",
+                        fmt_num(x),
+                        fmt_num(bbox.y),
+                        fmt_num(bbox.y + bbox.height)
+                    );
+                }
+            }
+        }
+        let d = d.trim_end();
+        if !d.is_empty() {
+            let (r, g, b) = style.border_color;
+            let _ = writeln!(
+                out,
+                "<path d=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"/>",
+                d,
+                rgb(Color::new(r, g, b)),
+                fmt_num(style.border_width)
+            );
+        }
+    }
+
+    out.push_str("</g>\n");
+}
+
+fn write_rect(out: &mut String, x: f32, y: f32, w: f32, h: f32, (r, g, b): (f32, f32, f32)) {
+    let _ = writeln!(
+        out,
+        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+        fmt_num(x),
+        fmt_num(y),
+        fmt_num(w),
+        fmt_num(h),
+        rgb(Color::new(r, g, b))
+    );
+}
+
+/// Map a PDF font name onto a web-safe CSS font stack.
+///
+/// Subset prefixes (`ABCDEF+`) and style suffixes are ignored; the family
+/// is classified as monospace, serif, symbol or sans-serif (the default).
+pub(crate) fn web_safe_font_family(pdf_font_name: &str) -> &'static str {
+    let name = strip_subset_prefix(pdf_font_name).to_ascii_lowercase();
+
+    if name.contains("courier")
+        || name.contains("mono")
+        || name.contains("consol")
+        || name.contains("menlo")
+    {
+        "'Courier New', Courier, monospace"
+    } else if name.contains("symbol") {
+        "Symbol, serif"
+    } else if name.contains("dingbat") {
+        "'Zapf Dingbats', serif"
+    } else if name.contains("sans") || name.contains("helvetica") || name.contains("arial") {
+        "Helvetica, Arial, sans-serif"
+    } else if name.contains("times")
+        || name.contains("serif")
+        || name.contains("roman")
+        || name.contains("georgia")
+        || name.contains("garamond")
+        || name.contains("cambria")
+        || name.contains("minion")
+    {
+        "'Times New Roman', Times, serif"
+    } else {
+        "Helvetica, Arial, sans-serif"
+    }
+}
+
+fn strip_subset_prefix(name: &str) -> &str {
+    match name.split_once('+') {
+        Some((prefix, rest))
+            if prefix.len() == 6 && prefix.bytes().all(|b| b.is_ascii_uppercase()) =>
+        {
+            rest
+        },
+        _ => name,
+    }
+}
+
+fn font_name_implies_bold(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    lower.contains("bold") || lower.contains("black") || lower.contains("heavy")
+}
+
+fn font_name_implies_italic(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    lower.contains("italic") || lower.contains("oblique")
+}
+
+fn svg_matrix(m: [f32; 6]) -> String {
+    format!(
+        "matrix({} {} {} {} {} {})",
+        fmt_num(m[0]),
+        fmt_num(m[1]),
+        fmt_num(m[2]),
+        fmt_num(m[3]),
+        fmt_num(m[4]),
+        fmt_num(m[5])
+    )
+}
+
+fn rgb(color: Color) -> String {
+    let channel = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", channel(color.r), channel(color.g), channel(color.b))
+}
+
+/// Format a coordinate with at most three decimals and no trailing zeros.
+fn fmt_num(v: f32) -> String {
+    if !v.is_finite() {
+        return "0".to_string();
+    }
+    let s = format!("{:.3}", v);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" || s.is_empty() {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+fn escape_xml(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            // Control characters other than tab/LF/CR are not allowed in XML 1.0.
+            c if (c as u32) < 0x20 && !matches!(c, '\t' | '\n' | '\r') => {},
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{FontSpec, TableCellContent, TableRowContent, TextStyle};
+    use crate::geometry::Rect;
+
+    fn page_with(children: Vec<ContentElement>) -> PdfPage {
+        let root = StructureElement {
+            structure_type: "Document".to_string(),
+            bbox: Rect::new(0.0, 0.0, 612.0, 792.0),
+            children,
+            reading_order: Some(0),
+            alt_text: None,
+            language: None,
+        };
+        PdfPage::from_structure(0, root, 612.0, 792.0)
+    }
+
+    #[test]
+    fn test_empty_page_svg() {
+        let svg = page_to_svg(&page_with(Vec::new()));
+        assert!(svg.starts_with("<?xml"));
+        assert!(svg.contains("viewBox=\"0 0 612 792\""));
+        assert!(svg.contains("matrix(1 0 0 -1 0 792)"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_text_is_escaped_and_mapped() {
+        let text = TextContent::new(
+            "a < b & \"c\"",
+            Rect::new(72.0, 700.0, 100.0, 12.0),
+            FontSpec::new("ABCDEF+TimesNewRomanPS-BoldMT", 12.0),
+            TextStyle::default(),
+        );
+        let svg = page_to_svg(&page_with(vec![ContentElement::Text(text)]));
+        assert!(svg.contains("a &lt; b &amp; &quot;c&quot;"));
+        assert!(svg.contains("font-family=\"'Times New Roman', Times, serif\""));
+        assert!(svg.contains("font-weight=\"700\""));
+        assert!(svg.contains("matrix(1 0 0 -1 72 700)"));
+    }
+
+    #[test]
+    fn test_path_svg() {
+        let path = PathContent::rect(10.0, 20.0, 30.0, 40.0)
+            .with_fill(Color::new(1.0, 0.0, 0.0))
+            .with_stroke_width(2.0);
+        let svg = page_to_svg(&page_with(vec![ContentElement::Path(path)]));
+        assert!(svg.contains("fill=\"#ff0000\""));
+        assert!(svg.contains("stroke-width=\"2\""));
+        assert!(svg.contains("Z"));
+    }
+
+    #[test]
+    fn test_png_image_embedded() {
+        let image = ImageContent::new(
+            Rect::new(0.0, 0.0, 50.0, 50.0),
+            ImageFormat::Png,
+            vec![0x89, b'P', b'N', b'G'],
+            1,
+            1,
+        );
+        let svg = page_to_svg(&page_with(vec![ContentElement::Image(image)]));
+        assert!(svg.contains("xmlns:xlink=\"http://www.w3.org/1999/xlink\""));
+        assert!(svg.contains("xlink:href=\"data:image/png;base64,"));
+        assert!(svg.contains("matrix(50 0 0 -50 0 50)"));
+    }
+
+    #[test]
+    fn test_raw_rgb_image_reencoded_as_png() {
+        let mut image = ImageContent::new(
+            Rect::new(0.0, 0.0, 10.0, 10.0),
+            ImageFormat::Raw,
+            vec![255, 0, 0, 0, 255, 0],
+            2,
+            1,
+        );
+        image.color_space = ColorSpace::RGB;
+        image.bits_per_component = 8;
+        assert!(image_data_uri(&image)
+            .unwrap()
+            .starts_with("data:image/png;base64,"));
+    }
+
+    #[test]
+    fn test_undisplayable_image_is_skipped() {
+        let image = ImageContent::new(
+            Rect::new(0.0, 0.0, 10.0, 10.0),
+            ImageFormat::Jbig2,
+            vec![1, 2, 3],
+            1,
+            1,
+        );
+        let svg = page_to_svg(&page_with(vec![ContentElement::Image(image)]));
+        assert!(!svg.contains("<image"));
+        assert!(svg.contains("image omitted"));
+    }
+
+    #[test]
+    fn test_table_svg() {
+        let mut table = TableContent::new(Rect::new(50.0, 400.0, 200.0, 40.0));
+        table.add_row(TableRowContent::header(vec![TableCellContent::header("Name")]));
+        table.add_row(TableRowContent::new(vec![TableCellContent::new("Alpha")]));
+        let svg = page_to_svg(&page_with(vec![ContentElement::Table(table)]));
+        assert!(svg.contains(">Name</text>"));
+        assert!(svg.contains(">Alpha</text>"));
+    }
+
+    #[test]
+    fn test_web_safe_font_family() {
+        assert_eq!(web_safe_font_family("Courier-Bold"), "'Courier New', Courier, monospace");
+        assert_eq!(web_safe_font_family("Helvetica"), "Helvetica, Arial, sans-serif");
+        assert_eq!(web_safe_font_family("MS Sans Serif"), "Helvetica, Arial, sans-serif");
+        assert_eq!(web_safe_font_family("Times-Roman"), "'Times New Roman', Times, serif");
+        assert_eq!(web_safe_font_family("Unknown"), "Helvetica, Arial, sans-serif");
+    }
+
+    #[test]
+    fn test_fmt_num() {
+        assert_eq!(fmt_num(1.0), "1");
+        assert_eq!(fmt_num(1.5), "1.5");
+        assert_eq!(fmt_num(-0.0001), "0");
+        assert_eq!(fmt_num(f32::NAN), "0");
+    }
+}
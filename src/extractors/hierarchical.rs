@@ -8,8 +8,11 @@
 //! PDF Spec: ISO 32000-1:2008, Section 14.7-14.8 (Logical Structure and Tagged PDF)
 
 use crate::document::PdfDocument;
-use crate::elements::{ContentElement, StructureElement};
+use crate::elements::{ColorSpace, ContentElement, ImageContent, ImageFormat, StructureElement};
 use crate::error::Result;
+use crate::extractors::images::{
+    ColorSpace as ExtractedColorSpace, ImageData, PdfImage, PixelFormat,
+};
 use crate::geometry::Rect;
 use std::collections::HashMap;
 
@@ -105,11 +108,21 @@ impl HierarchicalExtractor {
         };
 
         // Convert TextSpan to ContentElement::Text
-        let children: Vec<ContentElement> = text_spans
+        let mut children: Vec<ContentElement> = text_spans
             .into_iter()
             .map(|span| ContentElement::Text(TextContent::from(span)))
             .collect();
 
+        // Placed images follow the text. A page whose images can't be
+        // decoded still yields its text.
+        let images = document.extract_images(page_index).unwrap_or_default();
+        children.extend(
+            images
+                .iter()
+                .filter_map(image_content)
+                .map(ContentElement::Image),
+        );
+
         // Calculate bounding box from page dimensions or content
         let bbox = if children.is_empty() {
             // Default A4 page size in points: 595 x 842
@@ -164,6 +177,46 @@ impl HierarchicalExtractor {
     }
 }
 
+/// Convert an extracted image into a DOM element.
+///
+/// Only images with a known placement are kept. Raw samples are kept as
+/// 8-bit Gray/RGB/CMYK; anything else (e.g. unexpanded 1-bit data) is
+/// skipped because the DOM can't describe it.
+fn image_content(image: &PdfImage) -> Option<ImageContent> {
+    let bbox = *image.bbox()?;
+    let (format, data, color_space) = match image.data() {
+        ImageData::Jpeg(bytes) => {
+            let color_space = match image.color_space() {
+                ExtractedColorSpace::DeviceGray
+                | ExtractedColorSpace::CalGray
+                | ExtractedColorSpace::ICCBased(1) => ColorSpace::Gray,
+                ExtractedColorSpace::DeviceCMYK | ExtractedColorSpace::ICCBased(4) => {
+                    ColorSpace::CMYK
+                },
+                _ => ColorSpace::RGB,
+            };
+            (ImageFormat::Jpeg, bytes.clone(), color_space)
+        },
+        ImageData::Raw { pixels, format } => {
+            let expected =
+                image.width() as usize * image.height() as usize * format.bytes_per_pixel();
+            if pixels.len() != expected {
+                return None;
+            }
+            let color_space = match format {
+                PixelFormat::Grayscale => ColorSpace::Gray,
+                PixelFormat::RGB => ColorSpace::RGB,
+                PixelFormat::CMYK => ColorSpace::CMYK,
+            };
+            (ImageFormat::Raw, pixels.clone(), color_space)
+        },
+    };
+
+    let mut content = ImageContent::new(bbox, format, data, image.width(), image.height());
+    content.color_space = color_space;
+    Some(content)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
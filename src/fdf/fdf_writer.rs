@@ -4,6 +4,7 @@
 
 use crate::error::Result;
 use crate::extractors::forms::{FieldValue, FormField};
+use crate::writer::pdf_string::{name_token, text_string};
 use std::io::Write;
 use std::path::Path;
 
@@ -68,7 +69,7 @@ impl FdfField {
 
         // Field name /T
         dict.push_str("/T ");
-        dict.push_str(&encode_pdf_string(&self.name));
+        dict.push_str(&text_string(&self.name));
 
         // Field value /V
         if !matches!(self.value, FdfValue::None) {
@@ -94,7 +95,7 @@ impl FdfField {
 impl FdfValue {
     fn to_fdf_value(&self) -> String {
         match self {
-            FdfValue::Text(s) => encode_pdf_string(s),
+            FdfValue::Text(s) => text_string(s),
             FdfValue::Boolean(b) => {
                 if *b {
                     "/Yes".to_string()
@@ -102,9 +103,9 @@ impl FdfValue {
                     "/Off".to_string()
                 }
             },
-            FdfValue::Name(s) => format!("/{}", s),
+            FdfValue::Name(s) => name_token(s),
             FdfValue::Array(arr) => {
-                let items: Vec<String> = arr.iter().map(|s| encode_pdf_string(s)).collect();
+                let items: Vec<String> = arr.iter().map(|s| text_string(s)).collect();
                 format!("[ {} ]", items.join(" "))
             },
             FdfValue::None => "null".to_string(),
@@ -112,24 +113,6 @@ impl FdfValue {
     }
 }
 
-/// Encode a string as a PDF literal string.
-fn encode_pdf_string(s: &str) -> String {
-    let mut encoded = String::from("(");
-    for c in s.chars() {
-        match c {
-            '(' => encoded.push_str("\\("),
-            ')' => encoded.push_str("\\)"),
-            '\\' => encoded.push_str("\\\\"),
-            '\r' => encoded.push_str("\\r"),
-            '\n' => encoded.push_str("\\n"),
-            '\t' => encoded.push_str("\\t"),
-            _ => encoded.push(c),
-        }
-    }
-    encoded.push(')');
-    encoded
-}
-
 /// FDF file writer.
 ///
 /// Generates FDF (Forms Data Format) files for exporting form field data.
@@ -207,7 +190,7 @@ impl FdfWriter {
 
         // File specification (optional)
         if let Some(ref file_spec) = self.file_spec {
-            writeln!(output, "/F {}", encode_pdf_string(file_spec))?;
+            writeln!(output, "/F {}", text_string(file_spec))?;
         }
 
         // Fields array
@@ -242,9 +225,9 @@ mod tests {
 
     #[test]
     fn test_encode_pdf_string() {
-        assert_eq!(encode_pdf_string("Hello"), "(Hello)");
-        assert_eq!(encode_pdf_string("Hello (World)"), "(Hello \\(World\\))");
-        assert_eq!(encode_pdf_string("Line1\nLine2"), "(Line1\\nLine2)");
+        assert_eq!(text_string("Hello"), "(Hello)");
+        assert_eq!(text_string("Hello (World)"), "(Hello \\(World\\))");
+        assert_eq!(text_string("Line1\nLine2"), "(Line1\\nLine2)");
     }
 
     #[test]
@@ -280,4 +263,15 @@ mod tests {
         let dict_no = field_no.to_fdf_dict();
         assert!(dict_no.contains("/V /Off"));
     }
+
+    #[test]
+    fn test_fdf_non_ascii_encoding() {
+        let field = FdfField::new("Größe", FdfValue::Name("Größe L".into()));
+        let dict = field.to_fdf_dict();
+        assert!(dict.contains("/T <4772F6DF65>"));
+        assert!(dict.contains("/V /Gr#C3#B6#C3#9Fe#20L"));
+
+        let field = FdfField::new("name", FdfValue::Text("東京".into()));
+        assert!(field.to_fdf_dict().contains("/V <FEFF67714EAC>"));
+    }
 }
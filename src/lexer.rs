@@ -305,6 +305,11 @@ fn parse_hex_string(input: &[u8]) -> IResult<&[u8], Token<'_>> {
 /// Name objects can contain any characters encoded as #XX where XX is a
 /// two-digit hexadecimal code. For example, /A#20B becomes "A B".
 ///
+/// The decoded bytes are interpreted as UTF-8, the encoding the spec
+/// recommends for names. Bytes that are not valid UTF-8 fall back to
+/// Latin-1, so older files that escape single `#E9`-style bytes still decode
+/// to the intended character.
+///
 /// # Arguments
 ///
 /// * `name` - The raw name string with potential #XX sequences
@@ -320,9 +325,15 @@ fn parse_hex_string(input: &[u8]) -> IResult<&[u8], Token<'_>> {
 /// assert_eq!(decode_name_escapes("A#20B#23C"), "A B#C");
 /// assert_eq!(decode_name_escapes("Type"), "Type");
 /// assert_eq!(decode_name_escapes("A#"), "A#"); // Invalid sequence preserved
+/// assert_eq!(decode_name_escapes("#E9#83#A8"), "部"); // UTF-8
+/// assert_eq!(decode_name_escapes("F#E9"), "Fé"); // Latin-1 fallback
 /// ```
 pub fn decode_name_escapes(name: &str) -> String {
-    let mut result = String::with_capacity(name.len());
+    if !name.contains('#') {
+        return name.to_string();
+    }
+
+    let mut bytes = Vec::with_capacity(name.len());
     let mut chars = name.chars().peekable();
 
     while let Some(ch) = chars.next() {
@@ -336,29 +347,39 @@ pub fn decode_name_escapes(name: &str) -> String {
                 let hex_str = format!("{}{}", h1, h2);
                 if let Ok(byte) = u8::from_str_radix(&hex_str, 16) {
                     // Valid hex escape - decode it
-                    result.push(byte as char);
+                    bytes.push(byte);
                     continue;
                 }
                 // Invalid hex - treat as literal characters
-                result.push('#');
-                result.push(h1);
-                result.push(h2);
+                push_char_utf8(&mut bytes, '#');
+                push_char_utf8(&mut bytes, h1);
+                push_char_utf8(&mut bytes, h2);
             } else if let Some(h1) = hex1 {
                 // Only one character after # - invalid escape
-                result.push('#');
-                result.push(h1);
+                push_char_utf8(&mut bytes, '#');
+                push_char_utf8(&mut bytes, h1);
             } else {
                 // # at end of string
-                result.push('#');
+                push_char_utf8(&mut bytes, '#');
             }
         } else {
-            result.push(ch);
+            push_char_utf8(&mut bytes, ch);
         }
     }
 
+    let mut result = String::with_capacity(bytes.len());
+    for chunk in bytes.utf8_chunks() {
+        result.push_str(chunk.valid());
+        result.extend(chunk.invalid().iter().map(|&b| b as char));
+    }
     result
 }
 
+fn push_char_utf8(bytes: &mut Vec<u8>, ch: char) {
+    let mut buf = [0u8; 4];
+    bytes.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+}
+
 /// Parse a name starting with /.
 ///
 /// Names are identifiers starting with / (PDF Ref 1.7, Section 3.2.4).
@@ -663,6 +684,16 @@ mod tests {
         assert_eq!(decode_name_escapes("A#ZZ"), "A#ZZ"); // Invalid hex
     }
 
+    #[test]
+    fn test_decode_name_escapes_utf8_with_latin1_fallback() {
+        assert_eq!(decode_name_escapes("#E9#83#A8#E7#BD#B2"), "部署");
+        assert_eq!(decode_name_escapes("Gr#C3#B6#C3#9Fe"), "Größe");
+        assert_eq!(decode_name_escapes("Gr#F6#DFe"), "Größe");
+        // Invalid UTF-8 bytes fall back individually; valid runs still decode
+        assert_eq!(decode_name_escapes("#E9-#C3#A9"), "é-é");
+        assert_eq!(decode_name_escapes("日#20本"), "日 本");
+    }
+
     // ========================================================================
     // Keyword Tests
     // ========================================================================
@@ -795,6 +795,7 @@ impl PyPdfDocument {
                 linearize,
                 incremental: false,
                 encryption: None,
+                update_mod_date: false,
             };
             editor
                 .save_with_options(path, options)
@@ -830,6 +831,7 @@ impl PyPdfDocument {
                 linearize,
                 incremental: false,
                 encryption: None,
+                update_mod_date: false,
             };
             let bytes = editor.save_to_bytes_with_options(options).map_err(|e| {
                 PyRuntimeError::new_err(format!("Failed to save PDF to bytes: {}", e))
@@ -116,9 +116,9 @@ mod tests {
         let mut d = HashMap::new();
         d.insert("Type".to_string(), Object::Name("Sig".to_string()));
         d.insert("SubFilter".to_string(), Object::Name("adbe.pkcs7.detached".to_string()));
-        d.insert("Name".to_string(), Object::String(signer.as_bytes().to_vec()));
-        d.insert("Reason".to_string(), Object::String(reason.as_bytes().to_vec()));
-        d.insert("M".to_string(), Object::String(when.as_bytes().to_vec()));
+        d.insert("Name".to_string(), Object::text_string(signer));
+        d.insert("Reason".to_string(), Object::text_string(reason));
+        d.insert("M".to_string(), Object::text_string(when));
         d.insert(
             "ByteRange".to_string(),
             Object::Array(vec![
@@ -155,4 +155,15 @@ mod tests {
         // document the negative case).
         let _ = field_obj;
     }
+
+    #[test]
+    fn signature_info_decodes_non_ascii_text_strings() {
+        let sig = sig_dict("Zoë Müller", "承認", "D:20260421120000Z");
+        let info = SignatureVerifier::new()
+            .extract_signature_info(&sig)
+            .unwrap();
+        assert_eq!(info.signer_name.as_deref(), Some("Zoë Müller"));
+        assert_eq!(info.reason.as_deref(), Some("承認"));
+        assert_eq!(info.signing_time.as_deref(), Some("D:20260421120000Z"));
+    }
 }
@@ -5,6 +5,7 @@
 use super::byterange::ByteRangeCalculator;
 use super::types::{DigestAlgorithm, SignOptions, SigningCredentials};
 use crate::error::{Error, Result};
+use crate::writer::pdf_string::text_string;
 
 #[cfg(feature = "signatures")]
 use sha2::{Digest, Sha256, Sha384, Sha512};
@@ -57,19 +58,19 @@ impl PdfSigner {
 
         // Optional fields
         if let Some(ref name) = self.options.name {
-            dict.push_str(&format!("/Name ({})\n", escape_pdf_string(name)));
+            dict.push_str(&format!("/Name {}\n", text_string(name)));
         }
 
         if let Some(ref reason) = self.options.reason {
-            dict.push_str(&format!("/Reason ({})\n", escape_pdf_string(reason)));
+            dict.push_str(&format!("/Reason {}\n", text_string(reason)));
         }
 
         if let Some(ref location) = self.options.location {
-            dict.push_str(&format!("/Location ({})\n", escape_pdf_string(location)));
+            dict.push_str(&format!("/Location {}\n", text_string(location)));
         }
 
         if let Some(ref contact) = self.options.contact_info {
-            dict.push_str(&format!("/ContactInfo ({})\n", escape_pdf_string(contact)));
+            dict.push_str(&format!("/ContactInfo {}\n", text_string(contact)));
         }
 
         // Signing time (M field)
@@ -375,23 +376,6 @@ fn bytes_to_hex(bytes: &[u8]) -> String {
     hex
 }
 
-/// Escape special characters in a PDF string.
-fn escape_pdf_string(s: &str) -> String {
-    let mut result = String::with_capacity(s.len() + 10);
-    for c in s.chars() {
-        match c {
-            '\\' => result.push_str("\\\\"),
-            '(' => result.push_str("\\("),
-            ')' => result.push_str("\\)"),
-            '\n' => result.push_str("\\n"),
-            '\r' => result.push_str("\\r"),
-            '\t' => result.push_str("\\t"),
-            _ => result.push(c),
-        }
-    }
-    result
-}
-
 /// Format current time as a PDF date string.
 fn format_pdf_date() -> String {
     use std::time::SystemTime;
@@ -423,11 +407,18 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_escape_pdf_string() {
-        assert_eq!(escape_pdf_string("Hello"), "Hello");
-        assert_eq!(escape_pdf_string("Hello (World)"), "Hello \\(World\\)");
-        assert_eq!(escape_pdf_string("Line1\nLine2"), "Line1\\nLine2");
-        assert_eq!(escape_pdf_string("Path\\to\\file"), "Path\\\\to\\\\file");
+    fn test_signature_dictionary_string_encoding() {
+        let creds = SigningCredentials::new(vec![], vec![]);
+        let opts = SignOptions {
+            name: Some("Jane (CFO)".to_string()),
+            location: Some("Zürich".to_string()),
+            reason: Some("承認".to_string()),
+            ..Default::default()
+        };
+        let dict = PdfSigner::new(creds, opts).build_signature_dictionary();
+        assert!(dict.contains("/Name (Jane \\(CFO\\))"));
+        assert!(dict.contains("/Location <5AFC72696368>"));
+        assert!(dict.contains("/Reason <FEFF627F8A8D>"));
     }
 
     #[test]
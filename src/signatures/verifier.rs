@@ -6,6 +6,7 @@ use super::byterange::ByteRangeCalculator;
 use super::types::{SignatureInfo, SignatureSubFilter, VerificationResult, VerificationStatus};
 use crate::error::{Error, Result};
 use crate::object::Object;
+use crate::writer::pdf_string::decode_text_string;
 
 /// Verifier for PDF digital signatures.
 pub struct SignatureVerifier {
@@ -50,27 +51,27 @@ impl SignatureVerifier {
 
         // Extract /Name
         if let Some(Object::String(name)) = dict.get("Name") {
-            info.signer_name = Some(String::from_utf8_lossy(name).to_string());
+            info.signer_name = Some(decode_text_string(name));
         }
 
         // Extract /M (signing time)
         if let Some(Object::String(time)) = dict.get("M") {
-            info.signing_time = Some(String::from_utf8_lossy(time).to_string());
+            info.signing_time = Some(decode_text_string(time));
         }
 
         // Extract /Reason
         if let Some(Object::String(reason)) = dict.get("Reason") {
-            info.reason = Some(String::from_utf8_lossy(reason).to_string());
+            info.reason = Some(decode_text_string(reason));
         }
 
         // Extract /Location
         if let Some(Object::String(location)) = dict.get("Location") {
-            info.location = Some(String::from_utf8_lossy(location).to_string());
+            info.location = Some(decode_text_string(location));
         }
 
         // Extract /ContactInfo
         if let Some(Object::String(contact)) = dict.get("ContactInfo") {
-            info.contact_info = Some(String::from_utf8_lossy(contact).to_string());
+            info.contact_info = Some(decode_text_string(contact));
         }
 
         // Extract /SubFilter
@@ -2927,6 +2927,7 @@ impl WasmPdfDocument {
             linearize: linearize.unwrap_or(false),
             incremental: false,
             encryption: None,
+            update_mod_date: false,
         };
         let editor_arc = self.ensure_editor()?;
         let mut editor = editor_arc
@@ -3,6 +3,7 @@
 //! Builds PDF content streams containing graphics and text operators
 //! according to PDF specification ISO 32000-1:2008 Section 8-9.
 
+use super::pdf_string;
 use crate::elements::{
     ContentElement, ImageContent, PathContent, PathOperation, StructureElement, TableCellAlign,
     TableContent, TextContent,
@@ -1578,29 +1579,11 @@ impl ContentStreamBuilder {
 
     /// Write an escaped PDF string for Base-14 font content streams (WinAnsiEncoding).
     ///
-    /// Iterates Unicode scalar values and maps each to its WinAnsi/Latin-1 byte
-    /// (code-point value for U+0000–U+00FF).  Characters above U+00FF cannot be
-    /// represented in WinAnsiEncoding and are replaced with '?'; those require an
-    /// embedded font with Identity-H encoding.
+    /// Maps each Unicode scalar value to its WinAnsi byte.  Characters that
+    /// cannot be represented in WinAnsiEncoding are replaced with '?'; those
+    /// require an embedded font with Identity-H encoding.
     fn write_escaped_string<W: Write>(&self, w: &mut W, text: &str) -> std::io::Result<()> {
-        for ch in text.chars() {
-            let cp = ch as u32;
-            if cp > 0xFF {
-                w.write_all(b"?")?;
-                continue;
-            }
-            let b = cp as u8;
-            match b {
-                b'(' => write!(w, "\\(")?,
-                b')' => write!(w, "\\)")?,
-                b'\\' => write!(w, "\\\\")?,
-                b'\n' => write!(w, "\\n")?,
-                b'\r' => write!(w, "\\r")?,
-                b'\t' => write!(w, "\\t")?,
-                _ => w.write_all(&[b])?,
-            }
-        }
-        Ok(())
+        w.write_all(&pdf_string::escape_literal_bytes(&pdf_string::winansi_bytes(text)))
     }
 }
 
@@ -51,6 +51,18 @@ pub struct DocumentMetadata {
     pub keywords: Option<String>,
     /// Creator application
     pub creator: Option<String>,
+    /// Producer application
+    pub producer: Option<String>,
+    /// Raw PDF creation date (e.g. `D:20260101120000Z`)
+    pub creation_date: Option<String>,
+    /// Raw PDF modification date (e.g. `D:20260101120000Z`)
+    pub mod_date: Option<String>,
+    /// Custom `/Info` entries `(key, value)`, written alongside the standard
+    /// keys in sorted key order. An entry named like a standard key (e.g.
+    /// `Title`) replaces it; a repeated key keeps its last value.
+    pub custom_info: Vec<(String, String)>,
+    /// Explicit trailer `/ID` pair `(permanent, changing)`.
+    pub file_id: Option<(Vec<u8>, Vec<u8>)>,
     /// PDF version (default: "1.7")
     pub version: Option<String>,
     /// When true, emit PDF/UA-1 tagged-PDF catalog entries (/MarkInfo,
@@ -99,6 +111,39 @@ impl DocumentMetadata {
         self
     }
 
+    /// Set producer application.
+    pub fn producer(mut self, producer: impl Into<String>) -> Self {
+        self.producer = Some(producer.into());
+        self
+    }
+
+    /// Set the raw PDF creation date string.
+    pub fn creation_date(mut self, date: impl Into<String>) -> Self {
+        self.creation_date = Some(date.into());
+        self
+    }
+
+    /// Set the raw PDF modification date string.
+    pub fn mod_date(mut self, date: impl Into<String>) -> Self {
+        self.mod_date = Some(date.into());
+        self
+    }
+
+    /// Add a custom `/Info` entry (e.g. `("Company", "Acme")`). Multiple
+    /// calls accumulate entries; see [`custom_info`](Self::custom_info) for
+    /// how duplicate keys are resolved.
+    pub fn info_entry(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.custom_info.push((key.into(), value.into()));
+        self
+    }
+
+    /// Set the trailer `/ID` pair explicitly. Without this no `/ID` is
+    /// written, so builds are reproducible byte-for-byte.
+    pub fn file_id(mut self, permanent: impl Into<Vec<u8>>, changing: impl Into<Vec<u8>>) -> Self {
+        self.file_id = Some((permanent.into(), changing.into()));
+        self
+    }
+
     /// Enable PDF/UA-1 tagging. When true, `DocumentBuilder::build` will emit
     /// `/MarkInfo`, `/StructTreeRoot`, `/Lang`, and `/ViewerPreferences` in the
     /// catalog. Has no effect on existing callers that do not call this method.
@@ -420,6 +465,22 @@ fn italic_font_name(base: &str) -> String {
     }
 }
 
+/// Font name and size used for a heading of the given level. Shared by
+/// [`FluentPageBuilder::heading`] and the flow layout in `flow_document`.
+pub(crate) fn heading_font(level: u8) -> (&'static str, f32) {
+    let size = match level {
+        1 => 24.0,
+        2 => 20.0,
+        3 => 16.0,
+        _ => 14.0,
+    };
+    let font = match level {
+        1 | 2 => "Helvetica-Bold",
+        _ => "Helvetica",
+    };
+    (font, size)
+}
+
 /// Page builder for adding content to a page with fluent API.
 pub struct FluentPageBuilder<'a> {
     builder: &'a mut DocumentBuilder,
@@ -540,6 +601,16 @@ impl<'a> FluentPageBuilder<'a> {
         // carry over automatically.
     }
 
+    /// Index of the page this builder is currently writing into.
+    pub(crate) fn page_index(&self) -> usize {
+        self.page_index
+    }
+
+    /// `(width, height)` of the current page in points.
+    pub(crate) fn page_dimensions(&self) -> (f32, f32) {
+        let page = &self.builder.pages[self.page_index];
+        (page.width, page.height)
+    }
     /// Current cursor X (points from left edge). Used by
     /// `StreamingTable` to anchor column offsets.
     pub(crate) fn cursor_x(&self) -> f32 {
@@ -713,16 +784,7 @@ impl<'a> FluentPageBuilder<'a> {
 
     /// Add a heading (larger, bold text).
     pub fn heading(self, level: u8, text: &str) -> Self {
-        let size = match level {
-            1 => 24.0,
-            2 => 20.0,
-            3 => 16.0,
-            _ => 14.0,
-        };
-        let font = match level {
-            1 | 2 => "Helvetica-Bold",
-            _ => "Helvetica",
-        };
+        let (font, size) = heading_font(level);
         self.font(font, size).text(text)
     }
 
@@ -2188,12 +2250,26 @@ impl<'a> FluentPageBuilder<'a> {
     }
 
     fn image_with_options(
-        self,
+        mut self,
         data: crate::writer::image_handler::ImageData,
         rect: Rect,
         alt_text: Option<String>,
         is_artifact: bool,
     ) -> Self {
+        self.push_image(data, rect, alt_text, is_artifact);
+        self
+    }
+
+    /// In-place image placement behind the `image_*` methods. Used
+    /// directly by `flow_document`, which holds its page builder by
+    /// `&mut` and can't consume it.
+    pub(crate) fn push_image(
+        &mut self,
+        data: crate::writer::image_handler::ImageData,
+        rect: Rect,
+        alt_text: Option<String>,
+        is_artifact: bool,
+    ) {
         use crate::elements::{
             ColorSpace as EColorSpace, ImageContent, ImageFormat as EImageFormat,
         };
@@ -2230,7 +2306,6 @@ impl<'a> FluentPageBuilder<'a> {
         let page = &mut self.builder.pages[self.page_index];
         content.reading_order = Some(page.elements.len());
         page.elements.push(ContentElement::Image(content));
-        self
     }
 
     /// Place a 1-D barcode (Code 128, EAN-13, QR, …) at `(x, y, w, h)`.
@@ -3003,6 +3078,11 @@ impl DocumentBuilder {
         if self.metadata.creator.is_some() {
             config.creator = self.metadata.creator.clone();
         }
+        config.producer = self.metadata.producer.clone();
+        config.creation_date = self.metadata.creation_date.clone();
+        config.mod_date = self.metadata.mod_date.clone();
+        config.custom_info = self.metadata.custom_info.clone();
+        config.file_id = self.metadata.file_id.clone();
         // F-1/F-2/F-4: wire tagged PDF settings into writer config
         config.tagged = self.metadata.tagged;
         config.language = self.metadata.language.clone();
@@ -3577,11 +3657,21 @@ fn compose_affine(outer: [f32; 6], inner: [f32; 6]) -> [f32; 6] {
 /// trait against a fixed font name. Lives here (not on FontManager itself)
 /// because FontMetrics is a table-renderer-owned abstraction the writer
 /// layer doesn't know about.
-struct FluentFontMetrics<'a> {
+pub(crate) struct FluentFontMetrics<'a> {
     manager: &'a FontManager,
     font_name: String,
 }
 
+impl<'a> FluentFontMetrics<'a> {
+    /// Measure with `manager` as if every cell were set in `font_name`.
+    pub(crate) fn new(manager: &'a FontManager, font_name: impl Into<String>) -> Self {
+        Self {
+            manager,
+            font_name: font_name.into(),
+        }
+    }
+}
+
 impl FontMetrics for FluentFontMetrics<'_> {
     fn text_width(&self, text: &str, font_size: f32) -> f32 {
         self.manager.text_width(text, &self.font_name, font_size)
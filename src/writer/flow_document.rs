@@ -0,0 +1,803 @@
+//! Flow-layout builder for paginated business documents.
+//!
+//! [`FlowDocument`] sits on top of [`DocumentBuilder`]: callers append
+//! blocks (sections, headings, paragraphs, tables, images) without any
+//! coordinates, and [`FlowDocument::build`] lays them out top-to-bottom
+//! inside the page margins. Whenever the next line, table row or image
+//! would cross the bottom margin a new page is started. Running headers
+//! and footers come from a [`PageTemplate`], sections become bookmarks,
+//! and when tagging is enabled every block is wrapped in its structure
+//! element (`H1`–`H6`, `P`, `Table` with `TR` / `TH` / `TD`, `Figure`).
+//!
+//! ```no_run
+//! use pdf_oxide::writer::{
+//!     Artifact, DocumentMetadata, FlowDocument, Table, TableCell,
+//! };
+//!
+//! let pdf = FlowDocument::new()
+//!     .metadata(DocumentMetadata::new().title("Invoice 2041").tagged_pdf_ua1())
+//!     .header(Artifact::right("{title}"))
+//!     .footer(Artifact::center("Page {page} of {pages}"))
+//!     .section("Invoice 2041")
+//!     .paragraph("Thank you for your order. Payment is due within 30 days.")
+//!     .table(
+//!         Table::new(vec![
+//!             vec![TableCell::header("Item"), TableCell::header("Amount")],
+//!             vec![TableCell::text("Consulting"), TableCell::number("1,200.00")],
+//!         ])
+//!         .with_header_row(),
+//!     )
+//!     .build()?;
+//! # Ok::<(), pdf_oxide::Error>(())
+//! ```
+
+use super::document_builder::{
+    heading_font, DocumentBuilder, DocumentMetadata, FluentFontMetrics, FluentPageBuilder,
+    PageSize, TextConfig,
+};
+use super::font_manager::TextLayout;
+use super::image_handler::ImageData;
+use super::page_template::{Artifact, PageTemplate};
+use super::table_renderer::{ColumnWidth, Table};
+use crate::elements::{ContentElement, FontSpec, StructureElement, TextContent};
+use crate::error::Result;
+use crate::geometry::Rect;
+use std::path::Path;
+
+/// Page margins for a [`FlowDocument`], in points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowMargins {
+    /// Distance from the top edge to the first line of content
+    pub top: f32,
+    /// Distance from the right edge to the end of a line
+    pub right: f32,
+    /// Distance from the bottom edge below which no content is placed
+    pub bottom: f32,
+    /// Distance from the left edge to the start of a line
+    pub left: f32,
+}
+
+impl FlowMargins {
+    /// Create margins from explicit top / right / bottom / left values.
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// The same margin on all four sides.
+    pub fn uniform(margin: f32) -> Self {
+        Self::new(margin, margin, margin, margin)
+    }
+}
+
+impl Default for FlowMargins {
+    /// One inch on every side, matching [`DocumentBuilder`] pages.
+    fn default() -> Self {
+        Self::uniform(72.0)
+    }
+}
+
+/// A block of content queued on a [`FlowDocument`].
+#[derive(Debug, Clone)]
+enum FlowBlock {
+    /// Level-1 heading that also gets a top-level bookmark.
+    Section(String),
+    Heading(u8, String),
+    Paragraph(String),
+    Table(Table),
+    Image {
+        data: ImageData,
+        width: f32,
+        height: f32,
+        alt_text: Option<String>,
+    },
+    Space(f32),
+    PageBreak,
+}
+
+/// High-level builder for paginated documents (invoices, letters,
+/// reports) with automatic page breaks.
+///
+/// Blocks are stored in order and only laid out in [`Self::build`], so the
+/// builder can be assembled piecemeal and cloned as a template.
+#[derive(Debug, Clone)]
+pub struct FlowDocument {
+    page_size: PageSize,
+    margins: FlowMargins,
+    metadata: DocumentMetadata,
+    template: Option<PageTemplate>,
+    body: TextConfig,
+    blocks: Vec<FlowBlock>,
+}
+
+impl FlowDocument {
+    /// Create an empty Letter-sized document with one-inch margins and a
+    /// 12 pt Helvetica body.
+    pub fn new() -> Self {
+        Self {
+            page_size: PageSize::Letter,
+            margins: FlowMargins::default(),
+            metadata: DocumentMetadata::default(),
+            template: None,
+            body: TextConfig::default(),
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Set the size used for every page.
+    pub fn page_size(mut self, size: PageSize) -> Self {
+        self.page_size = size;
+        self
+    }
+
+    /// Set the page margins.
+    pub fn margins(mut self, margins: FlowMargins) -> Self {
+        self.margins = margins;
+        self
+    }
+
+    /// Set document metadata. Tagging is enabled through
+    /// [`DocumentMetadata::tagged_pdf_ua1`] or [`Self::tagged_pdf_ua1`].
+    pub fn metadata(mut self, metadata: DocumentMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Enable PDF/UA-1 tagging: blocks are emitted inside `H1`–`H6`, `P`,
+    /// `Table` and `Figure` structure elements.
+    pub fn tagged_pdf_ua1(mut self) -> Self {
+        self.metadata.tagged = true;
+        self
+    }
+
+    /// Set the running header drawn on every page. Placeholders such as
+    /// `{page}`, `{pages}` and `{title}` are resolved per page.
+    pub fn header(mut self, header: Artifact) -> Self {
+        self.template = Some(self.template.unwrap_or_default().header(header));
+        self
+    }
+
+    /// Set the running footer drawn on every page.
+    pub fn footer(mut self, footer: Artifact) -> Self {
+        self.template = Some(self.template.unwrap_or_default().footer(footer));
+        self
+    }
+
+    /// Use a full page template for running headers and footers (e.g. to
+    /// skip the first page).
+    ///
+    /// [`Self::build`] overwrites the template's left / right margins with
+    /// those from [`Self::margins`], so headers and footers line up with the
+    /// body; margins set on the template itself are ignored.
+    pub fn template(mut self, template: PageTemplate) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Set the font and size used for paragraphs.
+    pub fn body_font(mut self, name: &str, size: f32) -> Self {
+        self.body.font = name.to_string();
+        self.body.size = size;
+        self
+    }
+
+    /// Set the body line-height multiplier (default 1.2).
+    pub fn line_height(mut self, multiplier: f32) -> Self {
+        self.body.line_height = multiplier;
+        self
+    }
+
+    /// Start a section: a level-1 heading plus a top-level bookmark
+    /// pointing at the page the heading lands on.
+    pub fn section(mut self, title: impl Into<String>) -> Self {
+        self.blocks.push(FlowBlock::Section(title.into()));
+        self
+    }
+
+    /// Add a heading. Levels 1–6 map to `H1`–`H6` when tagged; a heading
+    /// is never left alone at the bottom of a page.
+    pub fn heading(mut self, level: u8, text: impl Into<String>) -> Self {
+        self.blocks.push(FlowBlock::Heading(level, text.into()));
+        self
+    }
+
+    /// Add a word-wrapped paragraph in the body font. Long paragraphs
+    /// continue on the next page.
+    pub fn paragraph(mut self, text: impl Into<String>) -> Self {
+        self.blocks.push(FlowBlock::Paragraph(text.into()));
+        self
+    }
+
+    /// Add a table laid out across the content width. Tables that overflow
+    /// are split between rows and leading header rows (see
+    /// [`Table::with_header_row`]) are repeated on each continuation page.
+    /// Rows spanning a split point (`rowspan`) are not kept together.
+    ///
+    /// When tagged, each page's part of the table is its own `Table`
+    /// structure element with `TR` rows and `TH` / `TD` cells; repeated
+    /// header rows and the cell backgrounds and borders are artifacts.
+    pub fn table(mut self, table: Table) -> Self {
+        self.blocks.push(FlowBlock::Table(table));
+        self
+    }
+
+    /// Add an image at `width` × `height` points, scaled down (keeping its
+    /// aspect ratio) if it exceeds the content area. Untitled images are
+    /// marked as decorative artifacts in tagged documents.
+    pub fn image(mut self, data: ImageData, width: f32, height: f32) -> Self {
+        self.blocks.push(FlowBlock::Image {
+            data,
+            width,
+            height,
+            alt_text: None,
+        });
+        self
+    }
+
+    /// Add an image with alternative text, emitted as a `/Figure` with
+    /// `/Alt` in tagged documents.
+    pub fn image_with_alt(
+        mut self,
+        data: ImageData,
+        width: f32,
+        height: f32,
+        alt_text: impl Into<String>,
+    ) -> Self {
+        self.blocks.push(FlowBlock::Image {
+            data,
+            width,
+            height,
+            alt_text: Some(alt_text.into()),
+        });
+        self
+    }
+
+    /// Add vertical space in points.
+    pub fn space(mut self, points: f32) -> Self {
+        self.blocks.push(FlowBlock::Space(points));
+        self
+    }
+
+    /// Force the following content onto a new page.
+    pub fn page_break(mut self) -> Self {
+        self.blocks.push(FlowBlock::PageBreak);
+        self
+    }
+
+    /// Lay out all blocks and return the PDF bytes.
+    pub fn build(self) -> Result<Vec<u8>> {
+        let tagged = self.metadata.tagged;
+        let mut doc = DocumentBuilder::new().metadata(self.metadata);
+        if let Some(template) = self.template {
+            doc = doc.template(template.margins(self.margins.left, self.margins.right));
+        }
+
+        let mut sections = Vec::new();
+        {
+            let mut layout =
+                FlowLayout::new(doc.page(self.page_size), self.margins, self.body, tagged);
+            for block in self.blocks {
+                if let FlowBlock::Section(title) = &block {
+                    let page_index = layout.place_heading(1, title);
+                    sections.push((title.clone(), page_index));
+                } else {
+                    layout.place(block);
+                }
+            }
+            layout.page.done();
+        }
+
+        for (title, page_index) in sections {
+            doc = doc.bookmark(title, page_index);
+        }
+        doc.build()
+    }
+
+    /// Build and save the PDF to a file.
+    pub fn save(self, path: impl AsRef<Path>) -> Result<()> {
+        let bytes = self.build()?;
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+}
+
+impl Default for FlowDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Layout state for one [`FlowDocument::build`] pass. `y` is the top of
+/// the remaining free area on the current page (PDF coordinates).
+struct FlowLayout<'a> {
+    page: FluentPageBuilder<'a>,
+    margins: FlowMargins,
+    body: TextConfig,
+    tagged: bool,
+    text_layout: TextLayout,
+    width: f32,
+    height: f32,
+    y: f32,
+    /// Nothing placed on the current page yet; an oversized block is then
+    /// placed anyway rather than breaking forever.
+    page_empty: bool,
+}
+
+impl<'a> FlowLayout<'a> {
+    fn new(
+        page: FluentPageBuilder<'a>,
+        margins: FlowMargins,
+        body: TextConfig,
+        tagged: bool,
+    ) -> Self {
+        let (width, height) = page.page_dimensions();
+        Self {
+            page,
+            margins,
+            body,
+            tagged,
+            text_layout: TextLayout::new(),
+            width,
+            height,
+            y: height - margins.top,
+            page_empty: true,
+        }
+    }
+
+    fn content_width(&self) -> f32 {
+        self.width - self.margins.left - self.margins.right
+    }
+
+    fn fits(&self, height: f32) -> bool {
+        self.y - height >= self.margins.bottom
+    }
+
+    fn break_page(&mut self) {
+        self.page.new_page_same_size_inplace();
+        self.y = self.height - self.margins.top;
+        self.page_empty = true;
+    }
+
+    fn place(&mut self, block: FlowBlock) {
+        match block {
+            FlowBlock::Section(title) => {
+                self.place_heading(1, &title);
+            },
+            FlowBlock::Heading(level, text) => {
+                self.place_heading(level, &text);
+            },
+            FlowBlock::Paragraph(text) => self.place_paragraph(&text),
+            FlowBlock::Table(table) => self.place_table(table),
+            FlowBlock::Image {
+                data,
+                width,
+                height,
+                alt_text,
+            } => self.place_image(data, width, height, alt_text),
+            FlowBlock::Space(points) => self.place_space(points),
+            FlowBlock::PageBreak => self.break_page(),
+        }
+    }
+
+    /// Advance by `points` without crossing the bottom margin. Space that
+    /// does not fit ends the current page and is not carried over; on a
+    /// fresh page it is dropped.
+    fn place_space(&mut self, points: f32) {
+        if self.fits(points) {
+            self.y -= points;
+            self.page_empty = false;
+        } else if !self.page_empty {
+            self.break_page();
+        }
+    }
+
+    /// Place a heading and return the index of the page it landed on.
+    fn place_heading(&mut self, level: u8, text: &str) -> usize {
+        let level = level.clamp(1, 6);
+        let (font, size) = heading_font(level);
+        let line_height = size * 1.2;
+        let lines = self
+            .text_layout
+            .wrap_text(text, font, size, self.content_width());
+
+        if !self.page_empty {
+            self.y -= size * 0.5;
+        }
+        // Keep with next: the heading plus one body line must fit.
+        let body_line = self.body.size * self.body.line_height;
+        let needed = line_height * lines.len().max(1) as f32 + body_line;
+        if !self.fits(needed) && !self.page_empty {
+            self.break_page();
+        }
+        let page_index = self.page.page_index();
+        self.place_lines(lines, font, size, line_height, &format!("H{}", level));
+        self.y -= size * 0.25;
+        page_index
+    }
+
+    fn place_paragraph(&mut self, text: &str) {
+        let font = self.body.font.clone();
+        let size = self.body.size;
+        let lines = self
+            .text_layout
+            .wrap_text(text, &font, size, self.content_width());
+        self.place_lines(lines, &font, size, size * self.body.line_height, "P");
+        // Same trailing gap as `FluentPageBuilder::paragraph`.
+        self.y -= size * 0.5;
+    }
+
+    /// Emit wrapped lines, breaking between lines. Lines that land on the
+    /// same page are grouped under one `tag` structure element.
+    fn place_lines(
+        &mut self,
+        lines: Vec<(String, f32)>,
+        font: &str,
+        size: f32,
+        line_height: f32,
+        tag: &str,
+    ) {
+        let mut group = Vec::new();
+        for (text, line_width) in lines {
+            if !self.fits(line_height) && !self.page_empty {
+                self.flush(tag, std::mem::take(&mut group));
+                self.break_page();
+            }
+            group.push(ContentElement::Text(TextContent {
+                text,
+                bbox: Rect::new(self.margins.left, self.y - size, line_width, size),
+                font: FontSpec {
+                    name: font.to_string(),
+                    size,
+                },
+                style: Default::default(),
+                reading_order: None,
+                artifact_type: None,
+                origin: None,
+                rotation_degrees: None,
+                matrix: None,
+            }));
+            self.y -= line_height;
+            self.page_empty = false;
+        }
+        self.flush(tag, group);
+    }
+
+    fn place_table(&mut self, table: Table) {
+        let content_width = self.content_width();
+        // Own layout engine: `metrics` outlives the page breaks below.
+        let text_layout = TextLayout::new();
+        let metrics =
+            FluentFontMetrics::new(text_layout.font_manager(), table.style.font_name.clone());
+        let layout = table.calculate_layout(content_width, &metrics);
+        if layout.row_heights.is_empty() {
+            return;
+        }
+
+        // Freeze the column widths so every continuation chunk lines up
+        // with the first one.
+        let mut fixed = table;
+        fixed.column_widths = layout
+            .column_widths
+            .iter()
+            .map(|w| ColumnWidth::Fixed(*w))
+            .collect();
+        fixed.width = Some(layout.total_width);
+
+        let header_count = fixed.rows.iter().take_while(|r| r.is_header).count();
+        let header_height: f32 = layout.row_heights[..header_count].iter().sum();
+        let total = fixed.rows.len();
+        let mut start = header_count;
+
+        loop {
+            let available = self.y - self.margins.bottom;
+            let mut end = start;
+            let mut height = header_height;
+            while end < total && height + layout.row_heights[end] <= available {
+                height += layout.row_heights[end];
+                end += 1;
+            }
+            let nothing_fits = if start < total {
+                end == start
+            } else {
+                header_height > available
+            };
+            if nothing_fits && !self.page_empty {
+                self.break_page();
+                continue;
+            }
+            if end == start && start < total {
+                // A single row taller than a whole page: place it anyway.
+                end = start + 1;
+            }
+
+            let mut chunk = fixed.clone();
+            chunk.rows = fixed.rows[..header_count]
+                .iter()
+                .chain(&fixed.rows[start..end])
+                .cloned()
+                .collect();
+            let chunk_layout = chunk.calculate_layout(content_width, &metrics);
+            if self.tagged {
+                // Header rows repeated on a continuation page are artifacts.
+                let repeated = if start > header_count {
+                    header_count
+                } else {
+                    0
+                };
+                let (artifacts, structure) =
+                    chunk.to_tagged_elements(self.margins.left, self.y, &chunk_layout, repeated);
+                for artifact in artifacts {
+                    self.page.push_element(artifact);
+                }
+                if let Some(mut structure) = structure {
+                    structure.reading_order = Some(self.page.page_element_count());
+                    self.page.push_element(ContentElement::Structure(structure));
+                }
+            } else {
+                let elements = chunk.to_content_elements(self.margins.left, self.y, &chunk_layout);
+                self.flush("Table", elements);
+            }
+            self.y -= chunk_layout.total_height;
+            self.page_empty = false;
+
+            start = end;
+            if start >= total {
+                break;
+            }
+            self.break_page();
+        }
+        self.y -= self.body.size * 0.5;
+    }
+
+    fn place_image(&mut self, data: ImageData, width: f32, height: f32, alt_text: Option<String>) {
+        let max_height = self.height - self.margins.top - self.margins.bottom;
+        let scale = (self.content_width() / width)
+            .min(max_height / height)
+            .min(1.0);
+        let (width, height) = (width * scale, height * scale);
+
+        if !self.fits(height) && !self.page_empty {
+            self.break_page();
+        }
+        let rect = Rect::new(self.margins.left, self.y - height, width, height);
+        let is_artifact = self.tagged && alt_text.is_none();
+        self.page.push_image(data, rect, alt_text, is_artifact);
+        self.y -= height + self.body.size * 0.5;
+        self.page_empty = false;
+    }
+
+    /// Push one block fragment onto the current page, wrapped in a `tag`
+    /// structure element when tagging is enabled.
+    fn flush(&mut self, tag: &str, elements: Vec<ContentElement>) {
+        if elements.is_empty() {
+            return;
+        }
+        if self.tagged {
+            let bbox = elements
+                .iter()
+                .map(ContentElement::bbox)
+                .reduce(|a, b| a.union(&b))
+                .unwrap_or_default();
+            let reading_order = Some(self.page.page_element_count());
+            self.page
+                .push_element(ContentElement::Structure(StructureElement {
+                    structure_type: tag.to_string(),
+                    bbox,
+                    children: elements,
+                    reading_order,
+                    alt_text: None,
+                    language: None,
+                }));
+        } else {
+            for mut element in elements {
+                let order = Some(self.page.page_element_count());
+                match &mut element {
+                    ContentElement::Text(t) => t.reading_order = order,
+                    ContentElement::Path(p) => p.reading_order = order,
+                    _ => {},
+                }
+                self.page.push_element(element);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::TableCell;
+
+    fn texts(elements: &[ContentElement]) -> Vec<String> {
+        let mut out = Vec::new();
+        for element in elements {
+            match element {
+                ContentElement::Text(t) => out.push(t.text.clone()),
+                ContentElement::Structure(s) => out.extend(texts(&s.children)),
+                _ => {},
+            }
+        }
+        out
+    }
+
+    fn lay_out(doc: FlowDocument) -> DocumentBuilder {
+        let mut builder = DocumentBuilder::new();
+        {
+            let mut layout = FlowLayout::new(
+                builder.page(doc.page_size),
+                doc.margins,
+                doc.body,
+                doc.metadata.tagged,
+            );
+            for block in doc.blocks {
+                layout.place(block);
+            }
+            layout.page.done();
+        }
+        builder
+    }
+
+    #[test]
+    fn test_long_paragraphs_break_onto_new_pages() {
+        let mut doc = FlowDocument::new();
+        for i in 0..80 {
+            doc = doc.paragraph(format!("Paragraph {i}"));
+        }
+        let builder = lay_out(doc);
+        assert!(builder.page_count() > 1);
+
+        // Every line stays above the bottom margin.
+        for idx in 0..builder.page_count() {
+            for element in builder.page_elements(idx) {
+                assert!(element.bbox().y >= 72.0 - f32::EPSILON);
+            }
+        }
+        let first = texts(builder.page_elements(0));
+        assert_eq!(first[0], "Paragraph 0");
+    }
+
+    #[test]
+    fn test_heading_is_kept_with_next_line() {
+        // Fill the page so only room for the heading itself remains.
+        let doc = FlowDocument::new()
+            .page_size(PageSize::Custom(300.0, 200.0))
+            .margins(FlowMargins::uniform(20.0))
+            .space(130.0)
+            .paragraph("filler")
+            .heading(2, "Totals")
+            .paragraph("Body");
+        let builder = lay_out(doc);
+        assert_eq!(builder.page_count(), 2);
+        assert_eq!(texts(builder.page_elements(1)), vec!["Totals", "Body"]);
+    }
+
+    #[test]
+    fn test_oversized_space_never_pushes_content_off_the_page() {
+        let doc = FlowDocument::new()
+            .paragraph("Before")
+            .space(1000.0)
+            .paragraph("After break")
+            .page_break()
+            .space(1000.0)
+            .paragraph("Top of fresh page");
+        let builder = lay_out(doc);
+        assert_eq!(builder.page_count(), 3);
+        assert_eq!(texts(builder.page_elements(1)), vec!["After break"]);
+        assert_eq!(texts(builder.page_elements(2)), vec!["Top of fresh page"]);
+        for idx in 0..builder.page_count() {
+            for element in builder.page_elements(idx) {
+                assert!(element.bbox().y >= 72.0 - f32::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_table_header_repeats_after_split() {
+        let mut rows = vec![vec![TableCell::header("Item"), TableCell::header("Qty")]];
+        for i in 0..60 {
+            rows.push(vec![TableCell::text(format!("Row {i}")), TableCell::number("1")]);
+        }
+        let doc = FlowDocument::new().table(Table::new(rows).with_header_row());
+        let builder = lay_out(doc);
+        assert!(builder.page_count() > 1);
+        for idx in 0..builder.page_count() {
+            assert_eq!(texts(builder.page_elements(idx))[0], "Item");
+        }
+        let all: Vec<String> = (0..builder.page_count())
+            .flat_map(|idx| texts(builder.page_elements(idx)))
+            .filter(|t| t.starts_with("Row "))
+            .collect();
+        assert_eq!(all.len(), 60);
+    }
+
+    #[test]
+    fn test_tagged_blocks_are_wrapped_in_structure_elements() {
+        let doc = FlowDocument::new()
+            .tagged_pdf_ua1()
+            .heading(1, "Title")
+            .paragraph("Body text");
+        let builder = lay_out(doc);
+        let tags: Vec<&str> = builder
+            .page_elements(0)
+            .iter()
+            .filter_map(|e| match e {
+                ContentElement::Structure(s) => Some(s.structure_type.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(tags, vec!["H1", "P"]);
+    }
+
+    #[test]
+    fn test_tagged_table_has_rows_and_cells_and_artifact_headers() {
+        use crate::extractors::text::ArtifactType;
+
+        let mut rows = vec![vec![TableCell::header("Item"), TableCell::header("Qty")]];
+        for i in 0..60 {
+            rows.push(vec![TableCell::text(format!("Row {i}")), TableCell::number("1")]);
+        }
+        let doc = FlowDocument::new()
+            .tagged_pdf_ua1()
+            .table(Table::new(rows).with_header_row());
+        let builder = lay_out(doc);
+        assert!(builder.page_count() > 1);
+
+        let table_of = |idx: usize| -> StructureElement {
+            builder
+                .page_elements(idx)
+                .iter()
+                .find_map(|e| match e {
+                    ContentElement::Structure(s) if s.structure_type == "Table" => Some(s.clone()),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        let cell_tags = |table: &StructureElement, row: usize| -> Vec<String> {
+            match &table.children[row] {
+                ContentElement::Structure(tr) => {
+                    assert_eq!(tr.structure_type, "TR");
+                    tr.children
+                        .iter()
+                        .map(|c| match c {
+                            ContentElement::Structure(cell) => cell.structure_type.clone(),
+                            _ => panic!("TR holds only cells"),
+                        })
+                        .collect()
+                },
+                _ => panic!("Table holds only rows"),
+            }
+        };
+
+        let first = table_of(0);
+        assert_eq!(cell_tags(&first, 0), vec!["TH", "TH"]);
+        assert_eq!(cell_tags(&first, 1), vec!["TD", "TD"]);
+        assert_eq!(texts(&first.children[..1]), vec!["Item", "Qty"]);
+
+        // On the continuation page the repeated header is an artifact
+        let second = table_of(1);
+        assert_eq!(cell_tags(&second, 0), vec!["TD", "TD"]);
+        assert!(!texts(&second.children).contains(&"Item".to_string()));
+        let header_artifact = builder.page_elements(1).iter().any(|e| {
+            matches!(e, ContentElement::Text(t)
+                if t.text == "Item"
+                    && matches!(t.artifact_type, Some(ArtifactType::Pagination(_))))
+        });
+        assert!(header_artifact);
+    }
+
+    #[test]
+    fn test_page_break_and_section_bookmarks_build() {
+        let bytes = FlowDocument::new()
+            .section("One")
+            .page_break()
+            .section("Two")
+            .build()
+            .unwrap();
+        let text = String::from_utf8_lossy(&bytes);
+        assert!(text.contains("/Outlines"));
+        assert!(text.contains("/Count 2"));
+    }
+}
@@ -8,6 +8,8 @@
 //! fallback appearances for compatibility.
 
 use crate::geometry::Rect;
+use crate::writer::font_manager::FontManager;
+use crate::writer::pdf_string::content_text;
 
 /// Generator for form field appearance streams.
 ///
@@ -20,6 +22,8 @@ pub struct FormAppearanceGenerator {
     border_color: Option<(f32, f32, f32)>,
     /// Background color (RGB)
     background_color: Option<(f32, f32, f32)>,
+    /// Comb cell separator color (RGB); falls back to the border color
+    cell_separator_color: Option<(f32, f32, f32)>,
 }
 
 impl FormAppearanceGenerator {
@@ -41,6 +45,14 @@ impl FormAppearanceGenerator {
         self
     }
 
+    /// Set the color of the rules between comb cells.
+    ///
+    /// Without it, separators are drawn in the border color.
+    pub fn with_cell_separators(mut self, r: f32, g: f32, b: f32) -> Self {
+        self.cell_separator_color = Some((r, g, b));
+        self
+    }
+
     /// Generate appearance stream for a text field.
     ///
     /// # Arguments
@@ -60,6 +72,98 @@ impl FormAppearanceGenerator {
     ) -> String {
         let mut stream = String::new();
 
+        let height = rect.height;
+
+        self.push_text_frame(&mut stream, rect);
+
+        // Text
+        if !text.is_empty() {
+            let (r, g, b) = text_color;
+            let padding = 2.0;
+            let y_pos = (height - font_size) / 2.0; // Center vertically
+
+            stream.push_str("BT\n");
+            stream.push_str(&format!("{} {} {} rg\n", r, g, b));
+            stream.push_str(&format!("{} {} Tf\n", font_name, font_size));
+            stream.push_str(&format!("{} {} Td\n", padding, y_pos));
+            stream.push_str(&format!("({}) Tj\n", content_text(text)));
+            stream.push_str("ET\n");
+        }
+
+        stream
+    }
+
+    /// Generate appearance stream for a comb text field.
+    ///
+    /// The field is divided into `cells` equal-width cells separated by
+    /// vertical rules, and each character of `text` is centered in its
+    /// own cell. Characters beyond `cells` are not drawn.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - Field bounding rectangle
+    /// * `text` - Current text value
+    /// * `cells` - Number of cells (the field's `/MaxLen`)
+    /// * `font_name` - Font resource name (e.g., "/Helv")
+    /// * `font_size` - Font size in points
+    /// * `text_color` - RGB color (0.0-1.0)
+    pub fn comb_field_appearance(
+        &self,
+        rect: Rect,
+        text: &str,
+        cells: u32,
+        font_name: &str,
+        font_size: f32,
+        text_color: (f32, f32, f32),
+    ) -> String {
+        let mut stream = String::new();
+
+        let width = rect.width;
+        let height = rect.height;
+        let cells = cells.max(1);
+        let cell_width = width / cells as f32;
+
+        self.push_text_frame(&mut stream, rect);
+
+        // Cell separators
+        if let Some((r, g, b)) = self.cell_separator_color.or(self.border_color) {
+            let line_width = if self.border_width > 0.0 {
+                self.border_width
+            } else {
+                1.0
+            };
+            stream.push_str(&format!("{} {} {} RG\n", r, g, b));
+            stream.push_str(&format!("{} w\n", line_width));
+            for i in 1..cells {
+                let x = cell_width * i as f32;
+                stream.push_str(&format!("{} 0 m {} {} l S\n", x, x, height));
+            }
+        }
+
+        // One character per cell
+        if !text.is_empty() {
+            let (r, g, b) = text_color;
+            let fonts = FontManager::new();
+            let base_font = base14_for_resource(font_name);
+            let y_pos = (height - font_size) / 2.0;
+
+            stream.push_str("BT\n");
+            stream.push_str(&format!("{} {} {} rg\n", r, g, b));
+            stream.push_str(&format!("{} {} Tf\n", font_name, font_size));
+            for (i, ch) in text.chars().take(cells as usize).enumerate() {
+                let char_width = fonts.char_width(ch, base_font, font_size);
+                let x_pos = cell_width * i as f32 + (cell_width - char_width) / 2.0;
+                stream.push_str(&format!("1 0 0 1 {} {} Tm\n", x_pos, y_pos));
+                stream.push_str(&format!("({}) Tj\n", content_text(&ch.to_string())));
+            }
+            stream.push_str("ET\n");
+        }
+
+        stream
+    }
+
+    /// Draw the background and inset border shared by text-style fields.
+    fn push_text_frame(&self, stream: &mut String, rect: Rect) {
         let width = rect.width;
         let height = rect.height;
 
@@ -84,22 +188,6 @@ impl FormAppearanceGenerator {
                 ));
             }
         }
-
-        // Text
-        if !text.is_empty() {
-            let (r, g, b) = text_color;
-            let padding = 2.0;
-            let y_pos = (height - font_size) / 2.0; // Center vertically
-
-            stream.push_str("BT\n");
-            stream.push_str(&format!("{} {} {} rg\n", r, g, b));
-            stream.push_str(&format!("{} {} Tf\n", font_name, font_size));
-            stream.push_str(&format!("{} {} Td\n", padding, y_pos));
-            stream.push_str(&format!("({}) Tj\n", escape_pdf_string(text)));
-            stream.push_str("ET\n");
-        }
-
-        stream
     }
 
     /// Generate appearance stream for a checkbox (checked state).
@@ -312,7 +400,7 @@ impl FormAppearanceGenerator {
             let (r, g, b) = text_color;
             // Estimate text width (rough approximation)
             let approx_char_width = font_size * 0.6;
-            let text_width = caption.len() as f32 * approx_char_width;
+            let text_width = caption.chars().count() as f32 * approx_char_width;
             let x_pos = (width - text_width) / 2.0;
             let y_pos = (height - font_size) / 2.0;
 
@@ -320,7 +408,7 @@ impl FormAppearanceGenerator {
             stream.push_str(&format!("{} {} {} rg\n", r, g, b));
             stream.push_str(&format!("{} {} Tf\n", font_name, font_size));
             stream.push_str(&format!("{} {} Td\n", x_pos.max(2.0), y_pos));
-            stream.push_str(&format!("({}) Tj\n", escape_pdf_string(caption)));
+            stream.push_str(&format!("({}) Tj\n", content_text(caption)));
             stream.push_str("ET\n");
         }
 
@@ -365,20 +453,15 @@ fn circle_path(cx: f32, cy: f32, r: f32) -> String {
     )
 }
 
-/// Escape special characters in PDF strings.
-fn escape_pdf_string(s: &str) -> String {
-    let mut result = String::with_capacity(s.len());
-    for c in s.chars() {
-        match c {
-            '\\' => result.push_str("\\\\"),
-            '(' => result.push_str("\\("),
-            ')' => result.push_str("\\)"),
-            '\r' => result.push_str("\\r"),
-            '\n' => result.push_str("\\n"),
-            _ => result.push(c),
-        }
+/// Map an AcroForm default-resource font name (`/Helv`, `/Cour`, `/TiRo`)
+/// to the Base-14 font whose metrics it uses.
+fn base14_for_resource(font_name: &str) -> &'static str {
+    match font_name.trim_start_matches('/') {
+        "Cour" => "Courier",
+        "TiRo" => "Times-Roman",
+        "ZaDb" => "ZapfDingbats",
+        _ => "Helvetica",
     }
-    result
 }
 
 #[cfg(test)]
@@ -386,10 +469,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_escape_pdf_string() {
-        assert_eq!(escape_pdf_string("Hello"), "Hello");
-        assert_eq!(escape_pdf_string("Hello (World)"), "Hello \\(World\\)");
-        assert_eq!(escape_pdf_string("Back\\slash"), "Back\\\\slash");
+    fn test_non_ascii_caption_encoding() {
+        let gen = FormAppearanceGenerator::new();
+        let rect = Rect::new(0.0, 0.0, 80.0, 25.0);
+        let stream = gen.button_appearance(rect, "Envoyé (€)", "/Helv", 12.0, (0.0, 0.0, 0.0));
+
+        // WinAnsi bytes as octal escapes, never raw UTF-8
+        assert!(stream.contains("(Envoy\\351 \\(\\200\\)) Tj"));
+        assert!(stream.is_ascii());
     }
 
     #[test]
@@ -492,4 +579,38 @@ mod tests {
         // Should not contain text operations for empty text
         assert!(!stream.contains("BT"));
     }
+
+    #[test]
+    fn test_comb_field_appearance() {
+        let gen = FormAppearanceGenerator::new()
+            .with_background(1.0, 1.0, 1.0)
+            .with_border(1.0, 0.0, 0.0, 0.0)
+            .with_cell_separators(0.5, 0.5, 0.5);
+
+        let rect = Rect::new(0.0, 0.0, 100.0, 20.0);
+        let stream = gen.comb_field_appearance(rect, "12345", 5, "/Cour", 12.0, (0.0, 0.0, 0.0));
+
+        // Four separators between five cells
+        assert!(stream.contains("0.5 0.5 0.5 RG"));
+        assert_eq!(stream.matches(" l S").count(), 4);
+        assert!(stream.contains("20 0 m 20 20 l S"));
+
+        // Each character is placed on its own; Courier glyphs are 7.2pt
+        // wide at 12pt, so the first one starts (20 - 7.2) / 2 in.
+        assert_eq!(stream.matches("Tj").count(), 5);
+        assert!(stream.contains("1 0 0 1 6.4 4 Tm\n(1) Tj"));
+    }
+
+    #[test]
+    fn test_comb_field_appearance_truncates_to_cells() {
+        let gen = FormAppearanceGenerator::new();
+
+        let rect = Rect::new(0.0, 0.0, 60.0, 20.0);
+        let stream = gen.comb_field_appearance(rect, "ABCDEF", 3, "/Helv", 10.0, (0.0, 0.0, 0.0));
+
+        assert_eq!(stream.matches("Tj").count(), 3);
+        assert!(!stream.contains("(D)"));
+        // No border color and no separator color: no rules
+        assert!(!stream.contains(" l S"));
+    }
 }
@@ -0,0 +1,227 @@
+//! Input masks for text fields.
+//!
+//! An input mask restricts what can be typed into a text field using a
+//! generated keystroke script (`/AA /K`) and a matching `/MaxLen`.
+//!
+//! Mask characters follow Acrobat's arbitrary-mask convention:
+//!
+//! - `9` - a digit
+//! - `A` - a letter
+//! - `O` - a letter or digit
+//! - `X` - any character
+//! - anything else is a literal, inserted automatically while typing
+//!
+//! When a committed value does not match, the viewer shows a format hint
+//! such as `#####-####` rather than the raw mask. Presets carry a sample
+//! value as their hint; [`InputMask::with_format_hint`] overrides it.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use pdf_oxide::writer::form_fields::{InputMask, TextFieldWidget};
+//! use pdf_oxide::geometry::Rect;
+//!
+//! let zip = TextFieldWidget::new("zip", Rect::new(72.0, 700.0, 100.0, 20.0))
+//!     .with_input_mask(InputMask::us_zip())
+//!     .comb();
+//! ```
+
+/// A character mask for text field input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputMask {
+    /// Mask pattern
+    pattern: String,
+    /// Human-readable format shown when a value is rejected
+    hint: Option<String>,
+}
+
+impl InputMask {
+    /// Create a mask from a pattern such as `"999-99-9999"`.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            hint: None,
+        }
+    }
+
+    /// Set the format hint shown to the user when a value is rejected,
+    /// e.g. `"(555) 123-4567"`.
+    pub fn with_format_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// US phone number: `(555) 123-4567`.
+    pub fn us_phone() -> Self {
+        Self::new("(999) 999-9999").with_format_hint("(555) 123-4567")
+    }
+
+    /// International phone number in E.164 form (`+442079460958`), with
+    /// `digits` digits after the `+`.
+    pub fn international_phone(digits: usize) -> Self {
+        Self::new(format!("+{}", "9".repeat(digits)))
+    }
+
+    /// US ZIP code: `12345`.
+    pub fn us_zip() -> Self {
+        Self::new("99999").with_format_hint("12345")
+    }
+
+    /// US ZIP+4 code: `12345-6789`.
+    pub fn us_zip_plus4() -> Self {
+        Self::new("99999-9999").with_format_hint("12345-6789")
+    }
+
+    /// Canadian postal code: `K1A 0B1`.
+    pub fn ca_postal_code() -> Self {
+        Self::new("A9A 9A9").with_format_hint("K1A 0B1")
+    }
+
+    /// The mask pattern.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// The format hint shown when a value is rejected.
+    ///
+    /// Without an explicit hint one is derived from the pattern: `#` for a
+    /// digit, `A` for a letter, `*` for a letter or digit and `?` for any
+    /// character, so `"99999-9999"` reads `"#####-####"`.
+    pub fn format_hint(&self) -> String {
+        if let Some(hint) = &self.hint {
+            return hint.clone();
+        }
+        self.pattern
+            .chars()
+            .map(|k| match k {
+                '9' => '#',
+                'O' => '*',
+                'X' => '?',
+                other => other,
+            })
+            .collect()
+    }
+
+    /// Number of characters a complete value has (the field's `/MaxLen`).
+    pub fn max_length(&self) -> u32 {
+        self.pattern.chars().count() as u32
+    }
+
+    /// Generate the keystroke script enforcing this mask.
+    ///
+    /// While typing, each character must match its mask position; literal
+    /// positions are filled in automatically. On commit the value must be
+    /// empty or complete, otherwise the viewer rejects it with an alert.
+    pub fn keystroke_script(&self) -> String {
+        let mask = js_string_literal(&self.pattern);
+        let hint = js_string_literal(&self.format_hint());
+        format!(
+            r#"(function () {{
+  var m = {mask};
+  function lit(k) {{ return "9AOX".indexOf(k) < 0; }}
+  function ok(c, k) {{
+    if (k == "9") return c >= "0" && c <= "9";
+    if (k == "A") return /[A-Za-z]/.test(c);
+    if (k == "O") return /[A-Za-z0-9]/.test(c);
+    if (k == "X") return true;
+    return c == k;
+  }}
+  if (event.willCommit) {{
+    var v = event.value;
+    var good = v.length == 0 || v.length == m.length;
+    for (var i = 0; good && i < v.length; i++) good = ok(v.charAt(i), m.charAt(i));
+    if (!good) {{
+      app.alert("Expected format: " + {hint});
+      event.rc = false;
+    }}
+    return;
+  }}
+  var pre = event.value.substring(0, event.selStart);
+  var post = event.value.substring(event.selEnd);
+  var ins = "";
+  var p = pre.length;
+  for (var j = 0; j < event.change.length; j++) {{
+    var c = event.change.charAt(j);
+    while (p < m.length && lit(m.charAt(p)) && c != m.charAt(p)) {{
+      ins += m.charAt(p);
+      p++;
+    }}
+    if (p >= m.length || !ok(c, m.charAt(p))) {{
+      event.rc = false;
+      return;
+    }}
+    ins += c;
+    p++;
+  }}
+  if (pre.length + ins.length + post.length > m.length) {{
+    event.rc = false;
+    return;
+  }}
+  event.change = ins;
+}})();"#
+        )
+    }
+}
+
+/// Quote `s` as a JavaScript double-quoted string literal.
+fn js_string_literal(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preset_masks() {
+        assert_eq!(InputMask::us_phone().pattern(), "(999) 999-9999");
+        assert_eq!(InputMask::us_phone().max_length(), 14);
+        assert_eq!(InputMask::us_zip().max_length(), 5);
+        assert_eq!(InputMask::us_zip_plus4().max_length(), 10);
+        assert_eq!(InputMask::ca_postal_code().pattern(), "A9A 9A9");
+        assert_eq!(InputMask::international_phone(11).max_length(), 12);
+    }
+
+    #[test]
+    fn test_keystroke_script_embeds_mask() {
+        let script = InputMask::us_zip_plus4().keystroke_script();
+        assert!(script.contains(r#"var m = "99999-9999";"#));
+        assert!(script.contains("event.willCommit"));
+        assert!(script.contains("event.rc = false"));
+    }
+
+    #[test]
+    fn test_format_hint() {
+        assert_eq!(InputMask::new("99999-9999").format_hint(), "#####-####");
+        assert_eq!(InputMask::new("OOX-A9").format_hint(), "**?-A#");
+        assert_eq!(InputMask::us_phone().format_hint(), "(555) 123-4567");
+        assert_eq!(InputMask::international_phone(3).format_hint(), "+###");
+        assert_eq!(
+            InputMask::new("999")
+                .with_format_hint("3 digits")
+                .format_hint(),
+            "3 digits"
+        );
+
+        let script = InputMask::us_zip_plus4().keystroke_script();
+        assert!(script.contains(r#"app.alert("Expected format: " + "12345-6789");"#));
+        assert!(!script.contains("+ m)"));
+    }
+
+    #[test]
+    fn test_js_string_literal_escapes() {
+        assert_eq!(js_string_literal(r#"a"b\c"#), r#""a\"b\\c""#);
+    }
+}
@@ -5,12 +5,13 @@
 //!
 //! # Supported Field Types
 //!
-//! - **Text Fields** (`TextFieldWidget`): Single-line and multiline text input
+//! - **Text Fields** (`TextFieldWidget`): Single-line and multiline text input,
+//!   comb fields and input masks (`InputMask`)
 //! - **Checkboxes** (`CheckboxWidget`): Boolean on/off fields
 //! - **Radio Buttons** (`RadioButtonGroup`): Mutually exclusive choices
 //! - **Combo Boxes** (`ComboBoxWidget`): Dropdown selection lists
 //! - **List Boxes** (`ListBoxWidget`): Scrollable selection lists
-//! - **Push Buttons** (`PushButtonWidget`): Action triggers (submit, reset)
+//! - **Push Buttons** (`PushButtonWidget`): Action triggers (submit, reset, navigation)
 //!
 //! # Example
 //!
@@ -32,6 +33,7 @@ mod checkbox;
 mod choice_fields;
 mod field_flags;
 mod form_appearance;
+mod input_mask;
 mod push_button;
 mod radio_button;
 mod signature;
@@ -43,7 +45,9 @@ pub use field_flags::{
     ButtonFieldFlags, ChoiceFieldFlags, FieldFlags, TextAlignment, TextFieldFlags,
 };
 pub use form_appearance::FormAppearanceGenerator;
-pub use push_button::{FormAction, PushButtonWidget, SubmitFormFlags};
+pub use input_mask::InputMask;
+pub(crate) use push_button::resolve_action_pages;
+pub use push_button::{FormAction, NamedAction, PushButtonWidget, SubmitFormFlags};
 pub use radio_button::{RadioButtonGroup, RadioButtonWidget};
 pub use signature::SignatureWidget;
 pub use text_field::TextFieldWidget;
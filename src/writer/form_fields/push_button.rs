@@ -3,7 +3,8 @@
 //! Implements push button fields per ISO 32000-1:2008 Section 12.7.4.2.
 //!
 //! Push buttons trigger actions when clicked but don't retain a value.
-//! Common uses include submit and reset buttons.
+//! Common uses include submit and reset buttons, and page navigation
+//! ("Next section") in multi-page forms.
 //!
 //! # Example
 //!
@@ -21,11 +22,21 @@
 //! let reset = PushButtonWidget::new("reset", Rect::new(160.0, 100.0, 80.0, 25.0))
 //!     .with_caption("Reset")
 //!     .with_action(FormAction::ResetForm);
+//!
+//! // Validate, then jump to the next section (chained through /Next)
+//! let next = PushButtonWidget::new("next", Rect::new(248.0, 100.0, 80.0, 25.0))
+//!     .with_caption("Next")
+//!     .with_action(FormAction::JavaScript {
+//!         script: "validateSection1();".to_string(),
+//!     })
+//!     .with_next_action(FormAction::GoTo { page: 1, fit: None });
 //! ```
 
 use super::{ButtonFieldFlags, FormFieldEntry, FormFieldWidget};
+use crate::error::{Error, Result};
 use crate::geometry::Rect;
 use crate::object::{Object, ObjectRef};
+use crate::writer::outline_builder::FitMode;
 use std::collections::HashMap;
 
 /// A push button field widget.
@@ -82,11 +93,201 @@ pub enum FormAction {
         /// URI to navigate to
         uri: String,
     },
-    /// Go to a named destination.
+    /// Go to a named destination in this document (`/S /GoTo` with the
+    /// name as a string destination).
+    ///
+    /// Earlier releases wrote `/S /GoToR` with a name destination, which
+    /// viewers treat as a jump into another file and which lacks the
+    /// required `/F` entry.
     GoToNamed {
         /// Destination name
         name: String,
     },
+    /// Go to a page in this document.
+    ///
+    /// The page index is resolved to a page reference when the document
+    /// is written; an index past the last page makes `PdfWriter::finish` fail.
+    GoTo {
+        /// Page index (0-indexed)
+        page: usize,
+        /// Optional fit mode (defaults to `/Fit`)
+        fit: Option<FitMode>,
+    },
+    /// Execute a predefined viewer navigation action.
+    Named {
+        /// The viewer action
+        action: NamedAction,
+    },
+    /// Perform several actions in order.
+    ///
+    /// The first action becomes the button's action and the rest are
+    /// chained through its `/Next` entry (ISO 32000-1 Section 12.6.2).
+    Sequence {
+        /// Actions to perform, in order
+        actions: Vec<FormAction>,
+    },
+}
+
+/// Predefined navigation actions for [`FormAction::Named`].
+///
+/// Per PDF spec Table 211.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamedAction {
+    /// Go to the next page
+    NextPage,
+    /// Go to the previous page
+    PrevPage,
+    /// Go to the first page
+    FirstPage,
+    /// Go to the last page
+    LastPage,
+}
+
+impl NamedAction {
+    /// Get the PDF name for this action.
+    pub fn pdf_name(&self) -> &'static str {
+        match self {
+            NamedAction::NextPage => "NextPage",
+            NamedAction::PrevPage => "PrevPage",
+            NamedAction::FirstPage => "FirstPage",
+            NamedAction::LastPage => "LastPage",
+        }
+    }
+}
+
+impl FormAction {
+    /// Build the action dictionary, chaining any sequence through `/Next`.
+    ///
+    /// Returns `Ok(None)` for an empty sequence.
+    fn build_dict(&self) -> Result<Option<HashMap<String, Object>>> {
+        let mut leaves = Vec::new();
+        self.collect_leaves(&mut leaves);
+
+        let mut iter = leaves.into_iter();
+        let Some(first) = iter.next() else {
+            return Ok(None);
+        };
+        let mut dict = first.build_single_dict()?;
+        let next = iter
+            .map(|action| action.build_single_dict().map(Object::Dictionary))
+            .collect::<Result<Vec<Object>>>()?;
+        match next.len() {
+            0 => {},
+            1 => {
+                dict.insert("Next".to_string(), next.into_iter().next().unwrap());
+            },
+            _ => {
+                dict.insert("Next".to_string(), Object::Array(next));
+            },
+        }
+        Ok(Some(dict))
+    }
+
+    /// Flatten (possibly nested) sequences into the actions they perform.
+    fn collect_leaves<'a>(&'a self, out: &mut Vec<&'a FormAction>) {
+        match self {
+            FormAction::Sequence { actions } => {
+                for action in actions {
+                    action.collect_leaves(out);
+                }
+            },
+            other => out.push(other),
+        }
+    }
+
+    /// Build the dictionary for a single, non-sequence action.
+    ///
+    /// Returns an error for a [`FormAction::Sequence`], which has no
+    /// dictionary of its own; [`Self::build_dict`] flattens those first.
+    fn build_single_dict(&self) -> Result<HashMap<String, Object>> {
+        let mut dict = HashMap::new();
+
+        match self {
+            FormAction::SubmitForm { url, flags } => {
+                dict.insert("S".to_string(), Object::Name("SubmitForm".to_string()));
+                dict.insert("F".to_string(), Object::text_string(url));
+                let flag_bits = flags.to_bits();
+                if flag_bits != 0 {
+                    dict.insert("Flags".to_string(), Object::Integer(flag_bits));
+                }
+            },
+            FormAction::ResetForm => {
+                dict.insert("S".to_string(), Object::Name("ResetForm".to_string()));
+            },
+            FormAction::JavaScript { script } => {
+                dict.insert("S".to_string(), Object::Name("JavaScript".to_string()));
+                dict.insert("JS".to_string(), Object::text_string(script));
+            },
+            FormAction::Uri { uri } => {
+                dict.insert("S".to_string(), Object::Name("URI".to_string()));
+                dict.insert("URI".to_string(), Object::text_string(uri));
+            },
+            FormAction::GoToNamed { name } => {
+                dict.insert("S".to_string(), Object::Name("GoTo".to_string()));
+                dict.insert("D".to_string(), Object::text_string(name));
+            },
+            FormAction::GoTo { page, fit } => {
+                // Page index placeholder, replaced with the page reference
+                // by `resolve_action_pages` once page objects exist.
+                dict.insert("S".to_string(), Object::Name("GoTo".to_string()));
+                dict.insert(
+                    "D".to_string(),
+                    fit.unwrap_or_default()
+                        .destination(Object::Integer(*page as i64)),
+                );
+            },
+            FormAction::Named { action } => {
+                dict.insert("S".to_string(), Object::Name("Named".to_string()));
+                dict.insert("N".to_string(), Object::Name(action.pdf_name().to_string()));
+            },
+            FormAction::Sequence { .. } => {
+                return Err(Error::InvalidOperation(
+                    "an action sequence has no single action dictionary".to_string(),
+                ));
+            },
+        }
+
+        Ok(dict)
+    }
+}
+
+/// Replace page-index placeholders in `GoTo` action destinations with
+/// page references, following the `/Next` chain.
+///
+/// Returns an error if an index is past the end of `page_refs`: integer
+/// pages are only valid in remote (`GoToR`) destinations.
+pub(crate) fn resolve_action_pages(action: &mut Object, page_refs: &[ObjectRef]) -> Result<()> {
+    match action {
+        Object::Dictionary(dict) => {
+            if matches!(dict.get("S"), Some(Object::Name(s)) if s == "GoTo") {
+                if let Some(Object::Array(dest)) = dict.get_mut("D") {
+                    if let Some(Object::Integer(index)) = dest.first() {
+                        let page_ref = usize::try_from(*index)
+                            .ok()
+                            .and_then(|i| page_refs.get(i))
+                            .ok_or_else(|| {
+                                Error::InvalidOperation(format!(
+                                    "GoTo action targets page {} but the document has {} pages",
+                                    index,
+                                    page_refs.len()
+                                ))
+                            })?;
+                        dest[0] = Object::Reference(*page_ref);
+                    }
+                }
+            }
+            if let Some(next) = dict.get_mut("Next") {
+                resolve_action_pages(next, page_refs)?;
+            }
+        },
+        Object::Array(actions) => {
+            for action in actions {
+                resolve_action_pages(action, page_refs)?;
+            }
+        },
+        _ => {},
+    }
+    Ok(())
 }
 
 /// Flags for form submission.
@@ -231,11 +432,31 @@ impl PushButtonWidget {
     }
 
     /// Set the action to perform when clicked.
+    ///
+    /// Replaces any previously set action; use [`Self::with_next_action`]
+    /// to chain further actions after it.
     pub fn with_action(mut self, action: FormAction) -> Self {
         self.action = Some(action);
         self
     }
 
+    /// Append an action to run after the current one (via `/Next`).
+    ///
+    /// Without a current action this behaves like [`Self::with_action`].
+    pub fn with_next_action(mut self, action: FormAction) -> Self {
+        self.action = Some(match self.action.take() {
+            None => action,
+            Some(FormAction::Sequence { mut actions }) => {
+                actions.push(action);
+                FormAction::Sequence { actions }
+            },
+            Some(current) => FormAction::Sequence {
+                actions: vec![current, action],
+            },
+        });
+        self
+    }
+
     /// Make the field read-only (button disabled).
     pub fn read_only(mut self) -> Self {
         self.flags |= ButtonFieldFlags::READ_ONLY;
@@ -280,38 +501,11 @@ impl PushButtonWidget {
     }
 
     /// Build the action dictionary.
-    fn build_action_dict(&self) -> Option<HashMap<String, Object>> {
-        self.action.as_ref().map(|action| {
-            let mut dict = HashMap::new();
-
-            match action {
-                FormAction::SubmitForm { url, flags } => {
-                    dict.insert("S".to_string(), Object::Name("SubmitForm".to_string()));
-                    dict.insert("F".to_string(), Object::text_string(url));
-                    let flag_bits = flags.to_bits();
-                    if flag_bits != 0 {
-                        dict.insert("Flags".to_string(), Object::Integer(flag_bits));
-                    }
-                },
-                FormAction::ResetForm => {
-                    dict.insert("S".to_string(), Object::Name("ResetForm".to_string()));
-                },
-                FormAction::JavaScript { script } => {
-                    dict.insert("S".to_string(), Object::Name("JavaScript".to_string()));
-                    dict.insert("JS".to_string(), Object::text_string(script));
-                },
-                FormAction::Uri { uri } => {
-                    dict.insert("S".to_string(), Object::Name("URI".to_string()));
-                    dict.insert("URI".to_string(), Object::text_string(uri));
-                },
-                FormAction::GoToNamed { name } => {
-                    dict.insert("S".to_string(), Object::Name("GoToR".to_string()));
-                    dict.insert("D".to_string(), Object::Name(name.clone()));
-                },
-            }
-
-            dict
-        })
+    fn build_action_dict(&self) -> Result<Option<HashMap<String, Object>>> {
+        match &self.action {
+            Some(action) => action.build_dict(),
+            None => Ok(None),
+        }
     }
 
     /// Build to a FormFieldEntry.
@@ -383,8 +577,12 @@ impl FormFieldWidget for PushButtonWidget {
         dict.insert("F".to_string(), Object::Integer(4));
 
         // Action
-        if let Some(action_dict) = self.build_action_dict() {
-            dict.insert("A".to_string(), Object::Dictionary(action_dict));
+        match self.build_action_dict() {
+            Ok(Some(action_dict)) => {
+                dict.insert("A".to_string(), Object::Dictionary(action_dict));
+            },
+            Ok(None) => {},
+            Err(e) => log::warn!("push button '{}': action not written: {}", self.name, e),
         }
 
         // Tooltip
@@ -492,7 +690,7 @@ mod tests {
                 script: "app.alert('Hello');".to_string(),
             });
 
-        let action_dict = button.build_action_dict().unwrap();
+        let action_dict = button.build_action_dict().unwrap().unwrap();
         assert_eq!(action_dict.get("S"), Some(&Object::Name("JavaScript".to_string())));
     }
 
@@ -553,4 +751,111 @@ mod tests {
         let pdf = SubmitFormFlags::pdf();
         assert!(pdf.to_bits() & (1 << 7) != 0);
     }
+
+    #[test]
+    fn test_push_button_named_action() {
+        let button = PushButtonWidget::new("next", Rect::new(72.0, 100.0, 80.0, 25.0)).with_action(
+            FormAction::Named {
+                action: NamedAction::NextPage,
+            },
+        );
+
+        let action_dict = button.build_action_dict().unwrap().unwrap();
+        assert_eq!(action_dict.get("S"), Some(&Object::Name("Named".to_string())));
+        assert_eq!(action_dict.get("N"), Some(&Object::Name("NextPage".to_string())));
+    }
+
+    #[test]
+    fn test_push_button_goto_named_destination() {
+        let button = PushButtonWidget::new("toc", Rect::new(72.0, 100.0, 80.0, 25.0)).with_action(
+            FormAction::GoToNamed {
+                name: "section2".to_string(),
+            },
+        );
+
+        let action_dict = button.build_action_dict().unwrap().unwrap();
+        assert_eq!(action_dict.get("S"), Some(&Object::Name("GoTo".to_string())));
+        assert_eq!(action_dict.get("D"), Some(&Object::text_string("section2")));
+    }
+
+    #[test]
+    fn test_push_button_goto_page_resolution() {
+        let button = PushButtonWidget::new("next", Rect::new(72.0, 100.0, 80.0, 25.0))
+            .with_action(FormAction::GoTo { page: 1, fit: None });
+
+        let mut action = Object::Dictionary(button.build_action_dict().unwrap().unwrap());
+        let page_refs = [ObjectRef::new(3, 0), ObjectRef::new(5, 0)];
+        resolve_action_pages(&mut action, &page_refs).unwrap();
+
+        let dict = action.as_dict().unwrap();
+        assert_eq!(
+            dict.get("D"),
+            Some(&Object::Array(vec![
+                Object::Reference(ObjectRef::new(5, 0)),
+                Object::Name("Fit".to_string()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_push_button_goto_page_out_of_range() {
+        let button = PushButtonWidget::new("next", Rect::new(72.0, 100.0, 80.0, 25.0))
+            .with_action(FormAction::ResetForm)
+            .with_next_action(FormAction::GoTo { page: 2, fit: None });
+
+        let mut action = Object::Dictionary(button.build_action_dict().unwrap().unwrap());
+        let page_refs = [ObjectRef::new(3, 0), ObjectRef::new(5, 0)];
+        assert!(resolve_action_pages(&mut action, &page_refs).is_err());
+    }
+
+    #[test]
+    fn test_push_button_action_chain() {
+        let button = PushButtonWidget::new("next", Rect::new(72.0, 100.0, 80.0, 25.0))
+            .with_action(FormAction::JavaScript {
+                script: "validate();".to_string(),
+            })
+            .with_next_action(FormAction::ResetForm)
+            .with_next_action(FormAction::GoTo { page: 0, fit: None });
+
+        let mut action = Object::Dictionary(button.build_action_dict().unwrap().unwrap());
+        resolve_action_pages(&mut action, &[ObjectRef::new(7, 0)]).unwrap();
+
+        let dict = action.as_dict().unwrap();
+        assert_eq!(dict.get("S"), Some(&Object::Name("JavaScript".to_string())));
+        let Some(Object::Array(next)) = dict.get("Next") else {
+            panic!("expected /Next array");
+        };
+        assert_eq!(next.len(), 2);
+        let goto = next[1].as_dict().unwrap();
+        assert_eq!(goto.get("S"), Some(&Object::Name("GoTo".to_string())));
+        let Some(Object::Array(dest)) = goto.get("D") else {
+            panic!("expected explicit destination");
+        };
+        assert_eq!(dest[0], Object::Reference(ObjectRef::new(7, 0)));
+    }
+
+    #[test]
+    fn test_push_button_single_next_action_is_dict() {
+        let button = PushButtonWidget::new("next", Rect::new(72.0, 100.0, 80.0, 25.0)).with_action(
+            FormAction::Sequence {
+                actions: vec![
+                    FormAction::ResetForm,
+                    FormAction::Named {
+                        action: NamedAction::FirstPage,
+                    },
+                ],
+            },
+        );
+
+        let action_dict = button.build_action_dict().unwrap().unwrap();
+        assert_eq!(action_dict.get("S"), Some(&Object::Name("ResetForm".to_string())));
+        assert!(matches!(action_dict.get("Next"), Some(Object::Dictionary(_))));
+
+        let empty = PushButtonWidget::new("noop", Rect::new(72.0, 100.0, 80.0, 25.0))
+            .with_action(FormAction::Sequence { actions: vec![] });
+        assert!(empty.build_action_dict().unwrap().is_none());
+
+        let nested = FormAction::Sequence { actions: vec![] };
+        assert!(matches!(nested.build_single_dict(), Err(Error::InvalidOperation(_))));
+    }
 }
@@ -5,18 +5,27 @@
 //! # Example
 //!
 //! ```ignore
-//! use pdf_oxide::writer::form_fields::TextFieldWidget;
+//! use pdf_oxide::writer::form_fields::{InputMask, TextFieldWidget};
 //! use pdf_oxide::geometry::Rect;
 //!
 //! let field = TextFieldWidget::new("username", Rect::new(72.0, 700.0, 200.0, 20.0))
 //!     .with_value("john_doe")
 //!     .with_max_length(50)
 //!     .required();
+//!
+//! // Five-cell ZIP code box that only accepts digits
+//! let zip = TextFieldWidget::new("zip", Rect::new(72.0, 660.0, 100.0, 20.0))
+//!     .with_input_mask(InputMask::us_zip())
+//!     .comb();
 //! ```
 
-use super::{FormFieldEntry, FormFieldWidget, TextAlignment, TextFieldFlags};
+use super::{
+    FormAppearanceGenerator, FormFieldEntry, FormFieldWidget, InputMask, TextAlignment,
+    TextFieldFlags,
+};
 use crate::geometry::Rect;
 use crate::object::{Object, ObjectRef};
+use crate::writer::acroform::AcroFormBuilder;
 use std::collections::HashMap;
 
 /// Build a PDF JavaScript action dictionary for /AA entries.
@@ -69,6 +78,8 @@ pub struct TextFieldWidget {
     validate: Option<String>,
     /// /AA /C — calculate JavaScript action
     calculate: Option<String>,
+    /// Comb cell separator color (RGB, 0.0-1.0); defaults to the border color
+    comb_separator_color: Option<(f32, f32, f32)>,
 }
 
 impl TextFieldWidget {
@@ -98,6 +109,7 @@ impl TextFieldWidget {
             format: None,
             validate: None,
             calculate: None,
+            comb_separator_color: None,
         }
     }
 
@@ -133,12 +145,30 @@ impl TextFieldWidget {
 
     /// Make this a comb field (evenly spaced character positions).
     ///
-    /// Note: `max_length` must be set for comb fields.
+    /// Note: `max_length` must be set for comb fields. The field gets an
+    /// appearance stream with one cell per character, separated by rules
+    /// in the border color (see [`Self::with_comb_separator_color`]).
     pub fn comb(mut self) -> Self {
         self.flags |= TextFieldFlags::COMB;
         self
     }
 
+    /// Set the color of the rules between comb cells (RGB, 0.0-1.0).
+    pub fn with_comb_separator_color(mut self, r: f32, g: f32, b: f32) -> Self {
+        self.comb_separator_color = Some((r, g, b));
+        self
+    }
+
+    /// Restrict input to a mask such as a phone number or postal code.
+    ///
+    /// Installs the mask's keystroke script (replacing any set with
+    /// [`Self::with_keystroke`]) and sets `max_length` to the mask length.
+    pub fn with_input_mask(mut self, mask: InputMask) -> Self {
+        self.keystroke = Some(mask.keystroke_script());
+        self.max_length = Some(mask.max_length());
+        self
+    }
+
     /// Make the field read-only.
     pub fn read_only(mut self) -> Self {
         self.flags |= TextFieldFlags::READ_ONLY;
@@ -260,6 +290,67 @@ impl TextFieldWidget {
         format!("/{} {} Tf {} {} {} rg", self.font_name, self.font_size, r, g, b)
     }
 
+    /// Build the normal appearance stream for a comb field.
+    ///
+    /// Returns `None` unless the field is a single-line, non-password comb
+    /// field with a `max_length`, the only case where the spec defines
+    /// the cell layout (ISO 32000-1 Table 228).
+    fn build_comb_appearance(&self) -> Option<Object> {
+        let cells = self.max_length.filter(|&n| n > 0)?;
+        if !self.flags.contains(TextFieldFlags::COMB)
+            || self
+                .flags
+                .intersects(TextFieldFlags::MULTILINE | TextFieldFlags::PASSWORD)
+        {
+            return None;
+        }
+
+        let mut generator = FormAppearanceGenerator::new();
+        if let Some((r, g, b)) = self.background_color {
+            generator = generator.with_background(r, g, b);
+        }
+        if let Some((r, g, b)) = self.border_color {
+            generator = generator.with_border(self.border_width, r, g, b);
+        }
+        if let Some((r, g, b)) = self.comb_separator_color {
+            generator = generator.with_cell_separators(r, g, b);
+        }
+
+        let bbox = Rect::new(0.0, 0.0, self.rect.width, self.rect.height);
+        let text = self.value.as_deref().unwrap_or("");
+        let content = generator.comb_field_appearance(
+            bbox,
+            text,
+            cells,
+            &format!("/{}", self.font_name),
+            self.font_size,
+            self.text_color,
+        );
+
+        let mut dict = HashMap::new();
+        dict.insert("Type".to_string(), Object::Name("XObject".to_string()));
+        dict.insert("Subtype".to_string(), Object::Name("Form".to_string()));
+        dict.insert(
+            "BBox".to_string(),
+            Object::Array(vec![
+                Object::Real(0.0),
+                Object::Real(0.0),
+                Object::Real(self.rect.width as f64),
+                Object::Real(self.rect.height as f64),
+            ]),
+        );
+        dict.insert(
+            "Resources".to_string(),
+            Object::Dictionary(AcroFormBuilder::build_default_resources()),
+        );
+        dict.insert("Length".to_string(), Object::Integer(content.len() as i64));
+
+        Some(Object::Stream {
+            dict,
+            data: bytes::Bytes::from(content.into_bytes()),
+        })
+    }
+
     /// Build to a FormFieldEntry for page integration.
     pub fn build_entry(&self, page_ref: ObjectRef) -> FormFieldEntry {
         FormFieldEntry {
@@ -413,6 +504,13 @@ impl FormFieldWidget for TextFieldWidget {
             dict.insert("MK".to_string(), Object::Dictionary(mk));
         }
 
+        // Appearance stream (comb fields draw their cells)
+        if let Some(normal) = self.build_comb_appearance() {
+            let mut ap = HashMap::new();
+            ap.insert("N".to_string(), normal);
+            dict.insert("AP".to_string(), Object::Dictionary(ap));
+        }
+
         dict
     }
 }
@@ -537,4 +635,58 @@ mod tests {
         assert!(field.field_flags() & TextFieldFlags::REQUIRED.bits() != 0);
         assert!(field.needs_appearance());
     }
+
+    #[test]
+    fn test_text_field_comb_appearance() {
+        let field = TextFieldWidget::new("zip", Rect::new(72.0, 700.0, 100.0, 20.0))
+            .with_max_length(5)
+            .comb()
+            .with_value("12345")
+            .with_comb_separator_color(0.5, 0.5, 0.5);
+
+        let dict = field.build_widget_dict(ObjectRef::new(10, 0));
+        let ap = dict.get("AP").and_then(|ap| ap.as_dict()).expect("AP dict");
+        let Some(Object::Stream {
+            dict: xobject,
+            data,
+        }) = ap.get("N")
+        else {
+            panic!("expected normal appearance stream");
+        };
+
+        assert_eq!(xobject.get("Subtype"), Some(&Object::Name("Form".to_string())));
+        assert!(xobject.contains_key("Resources"));
+        let content = String::from_utf8_lossy(data);
+        assert!(content.contains("0.5 0.5 0.5 RG"));
+        assert_eq!(content.matches(" l S").count(), 4);
+        assert_eq!(content.matches("Tj").count(), 5);
+    }
+
+    #[test]
+    fn test_text_field_comb_appearance_requires_max_length() {
+        let field = TextFieldWidget::new("code", Rect::new(72.0, 700.0, 100.0, 20.0)).comb();
+        assert!(!field
+            .build_widget_dict(ObjectRef::new(10, 0))
+            .contains_key("AP"));
+
+        let plain =
+            TextFieldWidget::new("name", Rect::new(72.0, 700.0, 100.0, 20.0)).with_max_length(5);
+        assert!(!plain
+            .build_widget_dict(ObjectRef::new(10, 0))
+            .contains_key("AP"));
+    }
+
+    #[test]
+    fn test_text_field_input_mask() {
+        let field = TextFieldWidget::new("phone", Rect::new(72.0, 700.0, 150.0, 20.0))
+            .with_input_mask(InputMask::us_phone());
+
+        assert_eq!(field.max_length, Some(14));
+
+        let dict = field.build_field_dict();
+        assert_eq!(dict.get("MaxLen"), Some(&Object::Integer(14)));
+        let aa = dict.get("AA").and_then(|aa| aa.as_dict()).expect("AA dict");
+        let keystroke = aa.get("K").and_then(|k| k.as_dict()).expect("K action");
+        assert_eq!(keystroke.get("S"), Some(&Object::Name("JavaScript".to_string())));
+    }
 }
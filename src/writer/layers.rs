@@ -140,13 +140,7 @@ impl Layer {
         dict.insert("Type".to_string(), Object::Name("OCG".to_string()));
         dict.insert(
             "Name".to_string(),
-            Object::String(
-                self.display_name
-                    .as_ref()
-                    .unwrap_or(&self.name)
-                    .as_bytes()
-                    .to_vec(),
-            ),
+            Object::text_string(self.display_name.as_ref().unwrap_or(&self.name)),
         );
 
         // Intent
@@ -51,6 +51,7 @@ pub mod barcode;
 mod content_stream;
 mod document_builder;
 mod embedded_files;
+mod flow_document;
 #[cfg(feature = "system-fonts")]
 mod font_discovery;
 mod font_manager;
@@ -70,6 +71,7 @@ pub mod outline_builder;
 mod page_labels;
 mod page_template;
 mod pattern;
+pub mod pdf_string;
 mod pdf_writer;
 mod richmedia;
 mod screen;
@@ -103,6 +105,7 @@ pub use document_builder::{
     TextConfig, TextRun, TextRunStyle,
 };
 pub use embedded_files::{AFRelationship, EmbeddedFile, EmbeddedFilesBuilder};
+pub use flow_document::{FlowDocument, FlowMargins};
 pub use font_manager::{
     EmbeddedFont, EmbeddedFontManager, FontFamily, FontInfo, FontManager, FontWeight, TextLayout,
 };
@@ -114,9 +117,9 @@ pub use font_discovery::{FontResolveError, FontStyle, ResolvedFont, SystemFontDb
 pub use font_shaping::{shape as shape_text, Direction as ShapeDirection, ShapedGlyph, ShapedRun};
 pub use form_fields::{
     ButtonFieldFlags, CheckboxWidget, ChoiceFieldFlags, ChoiceOption, ComboBoxWidget, FieldFlags,
-    FormAction, FormAppearanceGenerator, FormFieldEntry, FormFieldWidget, ListBoxWidget,
-    PushButtonWidget, RadioButtonGroup, RadioButtonWidget, SignatureWidget, SubmitFormFlags,
-    TextAlignment, TextFieldFlags, TextFieldWidget,
+    FormAction, FormAppearanceGenerator, FormFieldEntry, FormFieldWidget, InputMask, ListBoxWidget,
+    NamedAction, PushButtonWidget, RadioButtonGroup, RadioButtonWidget, SignatureWidget,
+    SubmitFormFlags, TextAlignment, TextFieldFlags, TextFieldWidget,
 };
 pub use freetext::FreeTextAnnotation;
 pub use graphics_state::{ExtGStateBuilder, SoftMask, SoftMaskSubtype};
@@ -178,7 +181,7 @@ pub use threed::{
     ThreeDFormat, ThreeDLighting, ThreeDProjection, ThreeDRenderMode, ThreeDStream, ThreeDView,
 };
 pub use watermark::{FixedPrintSettings, WatermarkAnnotation};
-pub use xmp_metadata::{iso_timestamp, XmpWriter};
+pub use xmp_metadata::{iso_timestamp, pdf_date_to_xmp, XmpWriter};
 
 use crate::elements::ContentElement;
 use crate::error::Result;
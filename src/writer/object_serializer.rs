@@ -3,6 +3,7 @@
 //! Serializes PDF objects to their byte representation according to
 //! PDF specification ISO 32000-1:2008.
 
+use super::pdf_string;
 use crate::encryption::EncryptionWriteHandler;
 use crate::object::{Object, ObjectRef};
 use std::collections::HashMap;
@@ -220,67 +221,14 @@ impl ObjectSerializer {
     /// Uses literal string syntax `(...)` with proper escaping,
     /// or hex string syntax `<...>` for binary data.
     fn write_string<W: Write>(&self, w: &mut W, data: &[u8]) -> std::io::Result<()> {
-        // Check if data is printable ASCII
-        let is_printable = data
-            .iter()
-            .all(|&b| b == b'\n' || b == b'\r' || b == b'\t' || (0x20..=0x7E).contains(&b));
-
-        if is_printable {
-            // Use literal string
-            write!(w, "(")?;
-            for &byte in data {
-                match byte {
-                    b'(' => write!(w, "\\(")?,
-                    b')' => write!(w, "\\)")?,
-                    b'\\' => write!(w, "\\\\")?,
-                    b'\n' => write!(w, "\\n")?,
-                    b'\r' => write!(w, "\\r")?,
-                    b'\t' => write!(w, "\\t")?,
-                    _ => w.write_all(&[byte])?,
-                }
-            }
-            write!(w, ")")
-        } else {
-            // Use hex string
-            write!(w, "<")?;
-            for byte in data {
-                write!(w, "{:02X}", byte)?;
-            }
-            write!(w, ">")
-        }
+        w.write_all(pdf_string::string_token(data).as_bytes())
     }
 
     /// Write a PDF name.
     ///
     /// Names start with `/` and escape special characters with `#xx`.
     fn write_name<W: Write>(&self, w: &mut W, name: &str) -> std::io::Result<()> {
-        write!(w, "/")?;
-        for byte in name.bytes() {
-            match byte {
-                // Regular characters (no escaping needed)
-                b'!'
-                | b'"'
-                | b'$'..=b'&'
-                | b'\''..=b'.'
-                | b'0'..=b'9'
-                | b';'
-                | b'<'
-                | b'>'
-                | b'?'
-                | b'@'
-                | b'A'..=b'Z'
-                | b'^'..=b'z'
-                | b'|'
-                | b'~' => {
-                    w.write_all(&[byte])?;
-                },
-                // Characters that need escaping
-                _ => {
-                    write!(w, "#{:02X}", byte)?;
-                },
-            }
-        }
-        Ok(())
+        w.write_all(pdf_string::name_token(name).as_bytes())
     }
 
     /// Write a PDF array.
@@ -76,6 +76,61 @@ pub enum FitMode {
     },
 }
 
+impl FitMode {
+    /// Build an explicit destination array (`[page /Fit ...]`) for `page`.
+    ///
+    /// `page` is normally a page reference; remote (`GoToR`) and
+    /// not-yet-resolved destinations use a page index instead.
+    pub(crate) fn destination(&self, page: Object) -> Object {
+        let mut arr = vec![page];
+
+        match self {
+            FitMode::Fit => {
+                arr.push(Object::Name("Fit".to_string()));
+            },
+            FitMode::FitH(top) => {
+                arr.push(Object::Name("FitH".to_string()));
+                arr.push(top.map(|t| Object::Real(t as f64)).unwrap_or(Object::Null));
+            },
+            FitMode::FitV(left) => {
+                arr.push(Object::Name("FitV".to_string()));
+                arr.push(left.map(|l| Object::Real(l as f64)).unwrap_or(Object::Null));
+            },
+            FitMode::FitR {
+                left,
+                bottom,
+                right,
+                top,
+            } => {
+                arr.push(Object::Name("FitR".to_string()));
+                arr.push(Object::Real(*left as f64));
+                arr.push(Object::Real(*bottom as f64));
+                arr.push(Object::Real(*right as f64));
+                arr.push(Object::Real(*top as f64));
+            },
+            FitMode::FitB => {
+                arr.push(Object::Name("FitB".to_string()));
+            },
+            FitMode::FitBH(top) => {
+                arr.push(Object::Name("FitBH".to_string()));
+                arr.push(top.map(|t| Object::Real(t as f64)).unwrap_or(Object::Null));
+            },
+            FitMode::FitBV(left) => {
+                arr.push(Object::Name("FitBV".to_string()));
+                arr.push(left.map(|l| Object::Real(l as f64)).unwrap_or(Object::Null));
+            },
+            FitMode::XYZ { left, top, zoom } => {
+                arr.push(Object::Name("XYZ".to_string()));
+                arr.push(left.map(|l| Object::Real(l as f64)).unwrap_or(Object::Null));
+                arr.push(top.map(|t| Object::Real(t as f64)).unwrap_or(Object::Null));
+                arr.push(zoom.map(|z| Object::Real(z as f64)).unwrap_or(Object::Null));
+            },
+        }
+
+        Object::Array(arr)
+    }
+}
+
 /// Text style for outline items.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct OutlineStyle {
@@ -482,52 +537,7 @@ impl OutlineBuilder {
 
     /// Build a destination array for a fit mode.
     fn build_destination(&self, page_ref: ObjectRef, fit: &FitMode) -> Object {
-        let mut arr = vec![Object::Reference(page_ref)];
-
-        match fit {
-            FitMode::Fit => {
-                arr.push(Object::Name("Fit".to_string()));
-            },
-            FitMode::FitH(top) => {
-                arr.push(Object::Name("FitH".to_string()));
-                arr.push(top.map(|t| Object::Real(t as f64)).unwrap_or(Object::Null));
-            },
-            FitMode::FitV(left) => {
-                arr.push(Object::Name("FitV".to_string()));
-                arr.push(left.map(|l| Object::Real(l as f64)).unwrap_or(Object::Null));
-            },
-            FitMode::FitR {
-                left,
-                bottom,
-                right,
-                top,
-            } => {
-                arr.push(Object::Name("FitR".to_string()));
-                arr.push(Object::Real(*left as f64));
-                arr.push(Object::Real(*bottom as f64));
-                arr.push(Object::Real(*right as f64));
-                arr.push(Object::Real(*top as f64));
-            },
-            FitMode::FitB => {
-                arr.push(Object::Name("FitB".to_string()));
-            },
-            FitMode::FitBH(top) => {
-                arr.push(Object::Name("FitBH".to_string()));
-                arr.push(top.map(|t| Object::Real(t as f64)).unwrap_or(Object::Null));
-            },
-            FitMode::FitBV(left) => {
-                arr.push(Object::Name("FitBV".to_string()));
-                arr.push(left.map(|l| Object::Real(l as f64)).unwrap_or(Object::Null));
-            },
-            FitMode::XYZ { left, top, zoom } => {
-                arr.push(Object::Name("XYZ".to_string()));
-                arr.push(left.map(|l| Object::Real(l as f64)).unwrap_or(Object::Null));
-                arr.push(top.map(|t| Object::Real(t as f64)).unwrap_or(Object::Null));
-                arr.push(zoom.map(|z| Object::Real(z as f64)).unwrap_or(Object::Null));
-            },
-        }
-
-        Object::Array(arr)
+        fit.destination(Object::Reference(page_ref))
     }
 }
 
@@ -0,0 +1,273 @@
+//! PDF string and name encoding.
+//!
+//! Every place that turns Rust text into PDF syntax goes through this module,
+//! so field names, values, options, metadata and bookmark titles are encoded
+//! the same way whether they end up in the object serializer, the editor's
+//! content-stream writer, an FDF file or a hand-built appearance stream.
+//!
+//! - Literal strings escape `(`, `)` and `\` (ISO 32000-1 §7.3.4.2).
+//! - Text strings use PDFDocEncoding, or UTF-16BE with a BOM when they contain
+//!   characters above U+00FF (§7.9.2.2).
+//! - Strings that are not printable ASCII fall back to hex syntax `<...>`.
+//! - Names are UTF-8 and escape delimiters, whitespace and non-ASCII bytes as
+//!   `#xx` (§7.3.5).
+//!
+//! # Example
+//!
+//! ```
+//! use pdf_oxide::writer::pdf_string::{name_token, text_string};
+//!
+//! assert_eq!(text_string("Total (USD)"), "(Total \\(USD\\))");
+//! assert_eq!(text_string("Ωmega"), "<FEFF03A9006D006500670061>");
+//! assert_eq!(name_token("Größe"), "/Gr#C3#B6#C3#9Fe");
+//! ```
+
+use crate::fonts::encoding::unicode_to_winansi;
+use crate::object::encode_pdf_text_string;
+
+/// Escape bytes for the inside of a literal string, leaving other bytes raw.
+///
+/// Only `(`, `)`, `\` and the line-ending/tab control characters are escaped.
+/// Use this when writing into a binary buffer such as a content stream.
+pub fn escape_literal_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(bytes.len() + 2);
+    for &b in bytes {
+        match b {
+            b'(' | b')' | b'\\' => {
+                out.push(b'\\');
+                out.push(b);
+            },
+            b'\n' => out.extend_from_slice(b"\\n"),
+            b'\r' => out.extend_from_slice(b"\\r"),
+            b'\t' => out.extend_from_slice(b"\\t"),
+            _ => out.push(b),
+        }
+    }
+    out
+}
+
+/// Escape bytes for the inside of a literal string as 7-bit text.
+///
+/// Like [`escape_literal_bytes`], but every byte outside printable ASCII is
+/// written as an octal escape (`\ddd`), so the result is safe to embed in a
+/// `String`.
+pub fn escape_literal(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() + 2);
+    for &b in bytes {
+        match b {
+            b'(' => out.push_str("\\("),
+            b')' => out.push_str("\\)"),
+            b'\\' => out.push_str("\\\\"),
+            b'\n' => out.push_str("\\n"),
+            b'\r' => out.push_str("\\r"),
+            b'\t' => out.push_str("\\t"),
+            0x08 => out.push_str("\\b"),
+            0x0C => out.push_str("\\f"),
+            0x20..=0x7E => out.push(b as char),
+            _ => out.push_str(&format!("\\{:03o}", b)),
+        }
+    }
+    out
+}
+
+/// Encode bytes as a literal string `(...)`.
+pub fn literal_string(bytes: &[u8]) -> String {
+    format!("({})", escape_literal(bytes))
+}
+
+/// Encode bytes as a hex string `<...>`.
+pub fn hex_string(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2 + 2);
+    out.push('<');
+    for b in bytes {
+        out.push_str(&format!("{:02X}", b));
+    }
+    out.push('>');
+    out
+}
+
+/// Encode bytes as a string token.
+///
+/// Printable ASCII (plus newline, carriage return and tab) is written as a
+/// literal string; anything else falls back to a hex string.
+pub fn string_token(bytes: &[u8]) -> String {
+    let printable = bytes
+        .iter()
+        .all(|&b| b == b'\n' || b == b'\r' || b == b'\t' || (0x20..=0x7E).contains(&b));
+    if printable {
+        literal_string(bytes)
+    } else {
+        hex_string(bytes)
+    }
+}
+
+/// Encode text as a PDF text string token.
+///
+/// Used for field names (`/T`), values, options, tooltips, metadata and
+/// bookmark titles.
+pub fn text_string(s: &str) -> String {
+    string_token(&encode_pdf_text_string(s))
+}
+
+/// Encode a name as a name token, including the leading `/`.
+///
+/// The name is encoded as UTF-8 (ISO 32000-1 §7.3.5). Delimiters
+/// (`( ) < > [ ] { } / %`), `#`, whitespace, control bytes and bytes outside
+/// printable ASCII are written as `#xx`. [`crate::lexer::decode_name_escapes`]
+/// reads the result back as UTF-8.
+pub fn name_token(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 1);
+    out.push('/');
+    for &b in name.as_bytes() {
+        match b {
+            b'(' | b')' | b'<' | b'>' | b'[' | b']' | b'{' | b'}' | b'/' | b'%' | b'#' => {
+                out.push_str(&format!("#{:02X}", b))
+            },
+            0x21..=0x7E => out.push(b as char),
+            _ => out.push_str(&format!("#{:02X}", b)),
+        }
+    }
+    out
+}
+
+/// Encode text for a Base-14 font in WinAnsiEncoding.
+///
+/// Characters that WinAnsiEncoding cannot represent are replaced with `?`;
+/// those need an embedded font with Identity-H encoding.
+pub fn winansi_bytes(s: &str) -> Vec<u8> {
+    s.chars()
+        .map(|c| unicode_to_winansi(c as u32).unwrap_or(b'?'))
+        .collect()
+}
+
+/// Escape text for a `(...) Tj` operand in an appearance or content stream
+/// that uses a Base-14 font.
+pub fn content_text(s: &str) -> String {
+    escape_literal(&winansi_bytes(s))
+}
+
+/// Decode a PDF text string into a Rust string.
+///
+/// Recognises UTF-16BE and UTF-16LE byte-order marks and the UTF-8 marker
+/// allowed by PDF 2.0; everything else is PDFDocEncoding. Bytes that
+/// PDFDocEncoding leaves undefined decode to U+FFFD.
+pub fn decode_text_string(bytes: &[u8]) -> String {
+    let utf16 = |data: &[u8], be: bool| -> String {
+        let units: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|c| {
+                if be {
+                    u16::from_be_bytes([c[0], c[1]])
+                } else {
+                    u16::from_le_bytes([c[0], c[1]])
+                }
+            })
+            .collect();
+        String::from_utf16_lossy(&units)
+    };
+
+    match bytes {
+        [0xFE, 0xFF, rest @ ..] => utf16(rest, true),
+        [0xFF, 0xFE, rest @ ..] => utf16(rest, false),
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8_lossy(rest).into_owned(),
+        _ => bytes
+            .iter()
+            .map(|&b| {
+                crate::fonts::font_dict::pdfdoc_encoding_lookup(b)
+                    .unwrap_or(char::REPLACEMENT_CHARACTER)
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal_escaping() {
+        assert_eq!(literal_string(b"a(b)c\\d"), "(a\\(b\\)c\\\\d)");
+        assert_eq!(literal_string(b"x\ny\tz"), "(x\\ny\\tz)");
+        assert_eq!(literal_string(&[b'A', 0xE9, 0x01]), "(A\\351\\001)");
+        assert_eq!(escape_literal_bytes(&[b'(', 0xF3, b')']), vec![b'\\', b'(', 0xF3, b'\\', b')']);
+    }
+
+    #[test]
+    fn test_string_token_hex_fallback() {
+        assert_eq!(string_token(b"Hello"), "(Hello)");
+        assert_eq!(string_token(&[0x00, 0xFF, 0x80]), "<00FF80>");
+    }
+
+    #[test]
+    fn test_text_string_encodings() {
+        assert_eq!(text_string("Name (first)"), "(Name \\(first\\))");
+        // Latin-1 stays single-byte PDFDocEncoding
+        assert_eq!(text_string("Lógico"), "<4CF36769636F>");
+        // Above U+00FF switches to UTF-16BE with BOM
+        assert_eq!(text_string("日本"), "<FEFF65E5672C>");
+    }
+
+    #[test]
+    fn test_name_token_escaping() {
+        assert_eq!(name_token("Helv"), "/Helv");
+        assert_eq!(name_token("A B"), "/A#20B");
+        assert_eq!(name_token("a/b#c"), "/a#2Fb#23c");
+        assert_eq!(name_token("é"), "/#C3#A9");
+        assert_eq!(name_token("a.b-c_d!\"$&'*+,;=?@^`|~"), "/a.b-c_d!\"$&'*+,;=?@^`|~");
+    }
+
+    #[test]
+    fn test_name_token_escapes_every_delimiter() {
+        for (c, esc) in [
+            ('(', "#28"),
+            (')', "#29"),
+            ('<', "#3C"),
+            ('>', "#3E"),
+            ('[', "#5B"),
+            (']', "#5D"),
+            ('{', "#7B"),
+            ('}', "#7D"),
+            ('/', "#2F"),
+            ('%', "#25"),
+            ('#', "#23"),
+            (' ', "#20"),
+            ('\t', "#09"),
+            ('\n', "#0A"),
+            ('\r', "#0D"),
+            ('\x0C', "#0C"),
+            ('\0', "#00"),
+            ('\x7F', "#7F"),
+        ] {
+            assert_eq!(name_token(&format!("a{}b", c)), format!("/a{}b", esc), "{:?}", c);
+        }
+        assert_eq!(name_token("Approved (QA)"), "/Approved#20#28QA#29");
+        assert_eq!(name_token("Cost <EUR>"), "/Cost#20#3CEUR#3E");
+    }
+
+    #[test]
+    fn test_name_token_round_trips_through_lexer() {
+        use crate::lexer::decode_name_escapes;
+
+        for name in ["Helv", "Approved (QA)", "A[1]", "Größe", "部署", "Größe日"] {
+            let token = name_token(name);
+            assert_eq!(decode_name_escapes(&token[1..]), name);
+        }
+    }
+
+    #[test]
+    fn test_content_text_winansi() {
+        assert_eq!(content_text("Café (€)"), "Caf\\351 \\(\\200\\)");
+        assert_eq!(content_text("中"), "?");
+    }
+
+    #[test]
+    fn test_decode_round_trip() {
+        for s in ["Plain", "Résumé", "Ünïcödé – 日本語", "emoji 🎉"] {
+            assert_eq!(decode_text_string(&encode_pdf_text_string(s)), s);
+        }
+        assert_eq!(decode_text_string(&[0xEF, 0xBB, 0xBF, 0xC3, 0xA9]), "é");
+        assert_eq!(decode_text_string(&[0xFF, 0xFE, 0x41, 0x00]), "A");
+        // 0x9F is undefined in PDFDocEncoding
+        assert_eq!(decode_text_string(&[b'A', 0x9F, b'B']), "A\u{FFFD}B");
+    }
+}
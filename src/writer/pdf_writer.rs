@@ -7,8 +7,8 @@ use super::acroform::AcroFormBuilder;
 use super::annotation_builder::{AnnotationBuilder, LinkAnnotation};
 use super::content_stream::{ContentStreamBuilder, StructElemRecord};
 use super::form_fields::{
-    CheckboxWidget, ComboBoxWidget, FormFieldEntry, ListBoxWidget, PushButtonWidget,
-    RadioButtonGroup, SignatureWidget, TextFieldWidget,
+    resolve_action_pages, CheckboxWidget, ComboBoxWidget, FormFieldEntry, ListBoxWidget,
+    PushButtonWidget, RadioButtonGroup, SignatureWidget, TextFieldWidget,
 };
 use super::freetext::FreeTextAnnotation;
 use super::ink::InkAnnotation;
@@ -21,9 +21,11 @@ use super::special_annotations::{
 use super::stamp::{StampAnnotation, StampType};
 use super::text_annotations::TextAnnotation;
 use super::text_markup::TextMarkupAnnotation;
+use super::xmp_metadata::{pdf_date_to_xmp, XmpWriter};
 use crate::annotation_types::{LineEndingStyle, TextAlignment, TextAnnotationIcon, TextMarkupType};
 use crate::elements::ContentElement;
 use crate::error::Result;
+use crate::extractors::xmp::XmpMetadata;
 use crate::geometry::Rect;
 use crate::object::{Object, ObjectRef};
 use std::collections::HashMap;
@@ -44,6 +46,20 @@ pub struct PdfWriterConfig {
     pub keywords: Option<String>,
     /// Creator application
     pub creator: Option<String>,
+    /// Producer application (`/Info.Producer`)
+    pub producer: Option<String>,
+    /// Raw PDF creation date (`D:YYYYMMDDHHmmSS...`)
+    pub creation_date: Option<String>,
+    /// Raw PDF modification date (`D:YYYYMMDDHHmmSS...`)
+    pub mod_date: Option<String>,
+    /// Custom `/Info` entries `(key, value)`. They share one dictionary with
+    /// the standard keys, which the serializer writes in sorted key order. A
+    /// key that duplicates a standard key (e.g. `Title`) replaces it, and a
+    /// key repeated in this list keeps its last value.
+    pub custom_info: Vec<(String, String)>,
+    /// Explicit trailer `/ID` pair `(permanent, changing)`. `None` omits
+    /// the `/ID` entry.
+    pub file_id: Option<(Vec<u8>, Vec<u8>)>,
     /// Whether to compress streams
     pub compress: bool,
     /// Document-level `/OpenAction` JavaScript — runs when the PDF is
@@ -71,6 +87,11 @@ impl Default for PdfWriterConfig {
             subject: None,
             keywords: None,
             creator: Some("pdf_oxide".to_string()),
+            producer: None,
+            creation_date: None,
+            mod_date: None,
+            custom_info: Vec::new(),
+            file_id: None,
             compress: false, // Disable compression for now (requires flate2)
             open_action_script: None,
             tagged: false,
@@ -99,6 +120,22 @@ impl PdfWriterConfig {
         self
     }
 
+    /// Add a custom `/Info` entry.
+    pub fn with_info_entry(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.custom_info.push((key.into(), value.into()));
+        self
+    }
+
+    /// Set the trailer `/ID` pair.
+    pub fn with_file_id(
+        mut self,
+        permanent: impl Into<Vec<u8>>,
+        changing: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.file_id = Some((permanent.into(), changing.into()));
+        self
+    }
+
     /// Enable or disable stream compression.
     ///
     /// When enabled, content streams and embedded data will be compressed
@@ -1499,6 +1536,9 @@ impl PdfWriter {
                 // Update widget dict with correct page reference
                 let mut widget_dict = field_entry.widget_dict.clone();
                 widget_dict.insert("P".to_string(), Object::Reference(page_ref));
+                if let Some(action) = widget_dict.get_mut("A") {
+                    resolve_action_pages(action, &page_obj_refs)?;
+                }
 
                 // Merge widget entries into field dict (merged field/widget)
                 for (key, value) in widget_dict {
@@ -1603,10 +1643,44 @@ impl PdfWriter {
             ("Count", ObjectSerializer::integer(self.pages.len() as i64)),
         ]);
 
+        // Widgets build their appearance streams (e.g. comb cells) inline
+        // in /AP; streams must be indirect, so move each into its own object.
+        let mut appearance_objects: Vec<(u32, Object)> = Vec::new();
+        for (_, field_obj) in form_field_objects.iter_mut() {
+            let Object::Dictionary(field_dict) = field_obj else {
+                continue;
+            };
+            if let Some(Object::Dictionary(ap)) = field_dict.get_mut("AP") {
+                for appearance in ap.values_mut() {
+                    if matches!(appearance, Object::Stream { .. }) {
+                        let ap_id = self.alloc_obj_id();
+                        let stream = std::mem::replace(
+                            appearance,
+                            Object::Reference(ObjectRef::new(ap_id, 0)),
+                        );
+                        appearance_objects.push((ap_id, stream));
+                    }
+                }
+            }
+        }
+
+        // Viewers honouring /NeedAppearances regenerate every field and
+        // discard our appearance streams, so only ask for regeneration when
+        // some field (other than an unsigned signature) has none.
+        let fields_have_appearances = form_field_objects.iter().all(|(_, obj)| match obj {
+            Object::Dictionary(dict) => {
+                dict.contains_key("AP")
+                    || matches!(dict.get("FT"), Some(Object::Name(ft)) if ft == "Sig")
+            },
+            _ => true,
+        });
+
         // Build AcroForm if there are form fields
         let acroform_id = if !all_field_refs.is_empty() {
             let id = self.alloc_obj_id();
-            let mut acroform = self.acroform.take().unwrap_or_default();
+            let mut acroform = self.acroform.take().unwrap_or_else(|| {
+                AcroFormBuilder::default().with_need_appearances(!fields_have_appearances)
+            });
             acroform.add_fields(all_field_refs);
             if self.has_signature_fields {
                 acroform = acroform.signatures_exist();
@@ -1869,14 +1943,7 @@ impl PdfWriter {
 
             // ISO 14289-1 §6.7.11: PDF/UA documents must carry an XMP metadata
             // stream in the document catalog with pdfuaid:part set to 1 (UA-1).
-            let title = self.config.title.as_deref().unwrap_or("").to_string();
-            let creator = self
-                .config
-                .creator
-                .as_deref()
-                .unwrap_or("pdf_oxide")
-                .to_string();
-            let xmp = build_pdfua_xmp(&title, &creator, &lang);
+            let xmp = build_pdfua_xmp(&self.config, &lang);
             let xmp_id = self.alloc_obj_id();
             let mut xmp_dict: HashMap<String, Object> = HashMap::new();
             xmp_dict.insert("Type".to_string(), Object::Name("Metadata".to_string()));
@@ -1908,9 +1975,24 @@ impl PdfWriter {
         if let Some(subject) = &self.config.subject {
             info_entries.push(("Subject", ObjectSerializer::string(subject)));
         }
+        if let Some(keywords) = &self.config.keywords {
+            info_entries.push(("Keywords", ObjectSerializer::string(keywords)));
+        }
         if let Some(creator) = &self.config.creator {
             info_entries.push(("Creator", ObjectSerializer::string(creator)));
         }
+        if let Some(producer) = &self.config.producer {
+            info_entries.push(("Producer", ObjectSerializer::string(producer)));
+        }
+        if let Some(date) = &self.config.creation_date {
+            info_entries.push(("CreationDate", ObjectSerializer::string(date)));
+        }
+        if let Some(date) = &self.config.mod_date {
+            info_entries.push(("ModDate", ObjectSerializer::string(date)));
+        }
+        for (key, value) in &self.config.custom_info {
+            info_entries.push((key.as_str(), ObjectSerializer::string(value)));
+        }
         let info_obj = ObjectSerializer::dict(info_entries);
 
         // Write all objects
@@ -1964,6 +2046,12 @@ impl PdfWriter {
             output.extend_from_slice(&serializer.serialize_indirect(*field_id, 0, field_obj));
         }
 
+        // Widget appearance streams
+        for (ap_id, ap_obj) in &appearance_objects {
+            xref_offsets.push((*ap_id, output.len()));
+            output.extend_from_slice(&serializer.serialize_indirect(*ap_id, 0, ap_obj));
+        }
+
         // AcroForm object (if present)
         if let Some(acroform_id) = acroform_id {
             if let Some(acroform_obj) = self.objects.get(&acroform_id) {
@@ -2029,11 +2117,22 @@ impl PdfWriter {
         }
 
         // Write trailer
-        let trailer = ObjectSerializer::dict(vec![
+        let mut trailer_entries = vec![
             ("Size", ObjectSerializer::integer(self.next_obj_id as i64)),
             ("Root", ObjectSerializer::reference(catalog_id, 0)),
             ("Info", ObjectSerializer::reference(info_id, 0)),
-        ]);
+        ];
+        if let Some((permanent, changing)) = &self.config.file_id {
+            // Binary identifiers: the serializer falls back to hex strings.
+            trailer_entries.push((
+                "ID",
+                ObjectSerializer::array(vec![
+                    Object::String(permanent.clone()),
+                    Object::String(changing.clone()),
+                ]),
+            ));
+        }
+        let trailer = ObjectSerializer::dict(trailer_entries);
 
         writeln!(output, "trailer")?;
         output.extend_from_slice(&serializer.serialize(&trailer));
@@ -2059,44 +2158,40 @@ impl Default for PdfWriter {
     }
 }
 
-/// Build a minimal XMP packet that satisfies ISO 14289-1 §6.7.11 (PDF/UA-1).
-/// Returns raw UTF-8 bytes (no BOM, no padding).
-fn build_pdfua_xmp(title: &str, creator: &str, lang: &str) -> Vec<u8> {
-    // Escape the three free-text fields for XML attribute/element contexts.
-    fn xml_escape(s: &str) -> String {
-        s.replace('&', "&amp;")
-            .replace('<', "&lt;")
-            .replace('>', "&gt;")
-            .replace('"', "&quot;")
-    }
-    let title_e = xml_escape(title);
-    let creator_e = xml_escape(creator);
-    let lang_e = xml_escape(lang);
-
-    let xmp = format!(
-        r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
-<x:xmpmeta xmlns:x="adobe:ns:meta/">
- <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
-  <rdf:Description rdf:about=""
-      xmlns:dc="http://purl.org/dc/elements/1.1/"
-      xmlns:xmp="http://ns.adobe.com/xap/1.0/"
-      xmlns:pdf="http://ns.adobe.com/pdf/1.3/"
-      xmlns:pdfuaid="http://www.aiim.org/pdfua/ns/id/">
-   <dc:title><rdf:Alt><rdf:li xml:lang="{lang}">{title}</rdf:li></rdf:Alt></dc:title>
-   <dc:creator><rdf:Seq><rdf:li>{creator}</rdf:li></rdf:Seq></dc:creator>
-   <xmp:CreatorTool>{creator}</xmp:CreatorTool>
-   <pdf:Producer>{creator}</pdf:Producer>
-   <pdfuaid:part>1</pdfuaid:part>
-   <pdfuaid:amd>2005</pdfuaid:amd>
-  </rdf:Description>
- </rdf:RDF>
-</x:xmpmeta>
-<?xpacket end="w"?>"#,
-        lang = lang_e,
-        title = title_e,
-        creator = creator_e,
-    );
-    xmp.into_bytes()
+/// Build the PDF/UA-1 XMP packet from the writer's document metadata.
+///
+/// The packet mirrors the `/Info` entries written by `finish` (title,
+/// author, subject, keywords, creator, producer and both dates) so the two
+/// stay consistent for PDF/A and PDF/UA metadata checks. Custom `/Info`
+/// entries are not mirrored; XMP would need an extension schema for them.
+fn build_pdfua_xmp(config: &PdfWriterConfig, lang: &str) -> Vec<u8> {
+    // A custom entry named like a standard key replaces it in `/Info`.
+    let info = |key: &str, standard: &Option<String>| {
+        config
+            .custom_info
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+            .or_else(|| standard.clone())
+    };
+    let metadata = XmpMetadata {
+        dc_title: info("Title", &config.title),
+        dc_creator: info("Author", &config.author).into_iter().collect(),
+        dc_description: info("Subject", &config.subject),
+        dc_language: Some(lang.to_string()),
+        xmp_creator_tool: info("Creator", &config.creator),
+        xmp_create_date: info("CreationDate", &config.creation_date)
+            .as_deref()
+            .and_then(pdf_date_to_xmp),
+        xmp_modify_date: info("ModDate", &config.mod_date)
+            .as_deref()
+            .and_then(pdf_date_to_xmp),
+        pdf_producer: info("Producer", &config.producer),
+        pdf_keywords: info("Keywords", &config.keywords),
+        ..XmpMetadata::default()
+    };
+    XmpWriter::new(metadata).pdfua_part(1).build_bytes()
 }
 
 /// Convert an [`elements::ImageContent`] (what `PageBuilder::add_element`
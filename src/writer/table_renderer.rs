@@ -935,6 +935,112 @@ impl Table {
         y: f32,
         layout: &TableLayout,
     ) -> Vec<crate::elements::ContentElement> {
+        self.render_elements(x, y, layout).0
+    }
+
+    /// Render the table as tagged content for PDF/UA output.
+    ///
+    /// Returns the cell backgrounds and borders as `Layout` artifacts, to be
+    /// placed on the page outside any structure element, and a `Table`
+    /// structure element with one `TR` per row and a `TH` (header rows) or
+    /// `TD` per cell holding that cell's text.
+    ///
+    /// The first `repeated_header_rows` rows are header rows repeated on a
+    /// continuation page: their text becomes `Pagination` artifacts and they
+    /// get no `TR`. The structure element is `None` when no other rows
+    /// remain.
+    pub(crate) fn to_tagged_elements(
+        &self,
+        x: f32,
+        y: f32,
+        layout: &TableLayout,
+        repeated_header_rows: usize,
+    ) -> (Vec<crate::elements::ContentElement>, Option<crate::elements::StructureElement>) {
+        use crate::elements::{ContentElement, StructureElement};
+        use crate::extractors::text::{ArtifactType, PaginationSubtype};
+        use crate::geometry::Rect;
+
+        let (elements, owners) = self.render_elements(x, y, layout);
+        let mut cells: Vec<Vec<Vec<ContentElement>>> = self
+            .rows
+            .iter()
+            .map(|row| vec![Vec::new(); row.cells.len()])
+            .collect();
+        let mut artifacts = Vec::new();
+        for (index, mut element) in elements.into_iter().enumerate() {
+            match owners[index] {
+                Some((row, cell)) if row >= repeated_header_rows => {
+                    cells[row][cell].push(element);
+                    continue;
+                },
+                Some(_) => {
+                    if let ContentElement::Text(text) = &mut element {
+                        text.artifact_type =
+                            Some(ArtifactType::Pagination(PaginationSubtype::Other));
+                    }
+                },
+                None => {
+                    if let ContentElement::Path(path) = &mut element {
+                        path.artifact_type = Some(ArtifactType::Layout);
+                    }
+                },
+            }
+            artifacts.push(element);
+        }
+
+        let structure = |tag: &str, bbox: Rect, children: Vec<ContentElement>| StructureElement {
+            structure_type: tag.to_string(),
+            bbox,
+            children,
+            ..Default::default()
+        };
+        let rows: Vec<ContentElement> = cells
+            .into_iter()
+            .enumerate()
+            .skip(repeated_header_rows)
+            .map(|(row_idx, row_cells)| {
+                let tag = if self.rows[row_idx].is_header {
+                    "TH"
+                } else {
+                    "TD"
+                };
+                let row_cells: Vec<ContentElement> = row_cells
+                    .into_iter()
+                    .enumerate()
+                    .map(|(cell_idx, content)| {
+                        let pos = &layout.cell_positions[row_idx][cell_idx];
+                        let bbox =
+                            Rect::new(x + pos.x, y - pos.y - pos.height, pos.width, pos.height);
+                        ContentElement::Structure(structure(tag, bbox, content))
+                    })
+                    .collect();
+                let bbox = row_cells
+                    .iter()
+                    .map(ContentElement::bbox)
+                    .reduce(|a, b| a.union(&b))
+                    .unwrap_or_default();
+                ContentElement::Structure(structure("TR", bbox, row_cells))
+            })
+            .collect();
+        if rows.is_empty() {
+            return (artifacts, None);
+        }
+        let bbox = rows
+            .iter()
+            .map(ContentElement::bbox)
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_default();
+        (artifacts, Some(structure("Table", bbox, rows)))
+    }
+
+    /// Render the table to `ContentElement`s, along with the `(row, cell)`
+    /// each element's text belongs to (`None` for backgrounds and borders).
+    fn render_elements(
+        &self,
+        x: f32,
+        y: f32,
+        layout: &TableLayout,
+    ) -> (Vec<crate::elements::ContentElement>, Vec<Option<(usize, usize)>>) {
         use crate::elements::{
             ContentElement, FontSpec, PathContent, PathOperation, TextContent, TextStyle,
         };
@@ -945,6 +1051,7 @@ impl Table {
         // going down a page).
         let table_top = y;
         let mut elements: Vec<ContentElement> = Vec::new();
+        let mut owners: Vec<Option<(usize, usize)>> = Vec::new();
 
         // ── Pass 1 ── backgrounds and cell borders ──────────────────
         for (row_idx, row) in self.rows.iter().enumerate() {
@@ -1049,6 +1156,8 @@ impl Table {
                     // first line sits at the cell's top-after-padding.
                     let line_top = cell_y - (line_idx as f32) * line_height;
 
+                    owners.resize(elements.len(), None);
+                    owners.push(Some((row_idx, cell_idx)));
                     elements.push(ContentElement::Text(TextContent {
                         text: line.clone(),
                         bbox: Rect::new(text_x, line_top, *line_width, font_size),
@@ -1095,7 +1204,8 @@ impl Table {
             }
         }
 
-        elements
+        owners.resize(elements.len(), None);
+        (elements, owners)
     }
 
     /// Push one `ContentElement::Path` per enabled border side. Used by
@@ -34,6 +34,7 @@
 use crate::annotation_types::AnnotationFlags;
 use crate::geometry::Rect;
 use crate::object::{Object, ObjectRef};
+use crate::writer::pdf_string::content_text;
 use std::collections::HashMap;
 
 /// A Watermark annotation per PDF spec Section 12.5.6.22.
@@ -265,7 +266,7 @@ impl WatermarkAnnotation {
         stream.push_str(&format!("{:.2} {:.2} Td\n", text_x.max(0.0), text_y.max(0.0)));
 
         // Show text
-        stream.push_str(&format!("({}) Tj\n", escape_pdf_string(&self.text)));
+        stream.push_str(&format!("({}) Tj\n", content_text(&self.text)));
 
         // End text
         stream.push_str("ET\n");
@@ -345,6 +346,7 @@ impl WatermarkAnnotation {
         f1.insert("Type".to_string(), Object::Name("Font".to_string()));
         f1.insert("Subtype".to_string(), Object::Name("Type1".to_string()));
         f1.insert("BaseFont".to_string(), Object::Name(self.font_name.clone()));
+        f1.insert("Encoding".to_string(), Object::Name("WinAnsiEncoding".to_string()));
         font_dict.insert("F1".to_string(), Object::Dictionary(f1));
         resources.insert("Font".to_string(), Object::Dictionary(font_dict));
 
@@ -377,20 +379,6 @@ impl WatermarkAnnotation {
     }
 }
 
-/// Escape special characters in PDF string.
-fn escape_pdf_string(s: &str) -> String {
-    let mut result = String::with_capacity(s.len());
-    for c in s.chars() {
-        match c {
-            '(' => result.push_str("\\("),
-            ')' => result.push_str("\\)"),
-            '\\' => result.push_str("\\\\"),
-            _ => result.push(c),
-        }
-    }
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -471,10 +459,10 @@ mod tests {
     }
 
     #[test]
-    fn test_escape_pdf_string() {
-        assert_eq!(escape_pdf_string("hello"), "hello");
-        assert_eq!(escape_pdf_string("test(1)"), "test\\(1\\)");
-        assert_eq!(escape_pdf_string("back\\slash"), "back\\\\slash");
+    fn test_appearance_text_escaping() {
+        let stream = WatermarkAnnotation::new("Entwurf (Prüfung)").build_appearance_stream();
+        let content = String::from_utf8(stream).unwrap();
+        assert!(content.contains("(Entwurf \\(Pr\\374fung\\)) Tj"));
     }
 
     #[test]
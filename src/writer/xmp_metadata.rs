@@ -13,16 +13,21 @@ const NS_DC: &str = "http://purl.org/dc/elements/1.1/";
 const NS_XMP: &str = "http://ns.adobe.com/xap/1.0/";
 const NS_PDF: &str = "http://ns.adobe.com/pdf/1.3/";
 const NS_XMP_RIGHTS: &str = "http://ns.adobe.com/xap/1.0/rights/";
+const NS_PDFUAID: &str = "http://www.aiim.org/pdfua/ns/id/";
 
 /// XMP metadata writer/builder.
 pub struct XmpWriter {
     metadata: XmpMetadata,
+    pdfua_part: Option<u32>,
 }
 
 impl XmpWriter {
     /// Create a new XMP writer from metadata.
     pub fn new(metadata: XmpMetadata) -> Self {
-        Self { metadata }
+        Self {
+            metadata,
+            pdfua_part: None,
+        }
     }
 
     /// Create a new XMP writer with default metadata.
@@ -30,7 +35,7 @@ impl XmpWriter {
         let mut metadata = XmpMetadata::new();
         metadata.xmp_creator_tool = Some("pdf_oxide".to_string());
         metadata.pdf_producer = Some(format!("pdf_oxide {}", env!("CARGO_PKG_VERSION")));
-        Self { metadata }
+        Self::new(metadata)
     }
 
     /// Set the document title.
@@ -105,6 +110,12 @@ impl XmpWriter {
         self
     }
 
+    /// Declare PDF/UA conformance (`pdfuaid:part`, ISO 14289-1 §5).
+    pub fn pdfua_part(mut self, part: u32) -> Self {
+        self.pdfua_part = Some(part);
+        self
+    }
+
     /// Build the XMP packet as an XML string.
     pub fn build(self) -> String {
         self.to_xml()
@@ -136,7 +147,11 @@ impl XmpWriter {
         xml.push_str(&format!("        xmlns:dc=\"{}\"\n", NS_DC));
         xml.push_str(&format!("        xmlns:xmp=\"{}\"\n", NS_XMP));
         xml.push_str(&format!("        xmlns:pdf=\"{}\"\n", NS_PDF));
-        xml.push_str(&format!("        xmlns:xmpRights=\"{}\">\n", NS_XMP_RIGHTS));
+        xml.push_str(&format!("        xmlns:xmpRights=\"{}\"", NS_XMP_RIGHTS));
+        if self.pdfua_part.is_some() {
+            xml.push_str(&format!("\n        xmlns:pdfuaid=\"{}\"", NS_PDFUAID));
+        }
+        xml.push_str(">\n");
 
         // Dublin Core properties
         if let Some(title) = &self.metadata.dc_title {
@@ -269,6 +284,10 @@ impl XmpWriter {
             ));
         }
 
+        if let Some(part) = self.pdfua_part {
+            xml.push_str(&format!("      <pdfuaid:part>{}</pdfuaid:part>\n", part));
+        }
+
         // Custom properties
         for (key, value) in &self.metadata.custom {
             xml.push_str(&format!("      <{}>{}</{}>\n", key, escape_xml(value), key));
@@ -307,6 +326,59 @@ fn escape_xml(s: &str) -> String {
         .replace('\'', "&apos;")
 }
 
+/// Convert a PDF date string (`D:YYYYMMDDHHmmSSOHH'mm'`, ISO 32000-1 §7.9.4)
+/// into the ISO 8601 form XMP uses, so `/Info` and XMP dates agree.
+///
+/// Missing components default to the start of the period; a date without a
+/// UTC offset stays without one. Returns `None` if `date` is not a PDF date.
+///
+/// ```
+/// use pdf_oxide::writer::pdf_date_to_xmp;
+///
+/// assert_eq!(pdf_date_to_xmp("D:20260101120000Z").as_deref(), Some("2026-01-01T12:00:00Z"));
+/// assert_eq!(
+///     pdf_date_to_xmp("D:20260226093000+01'00'").as_deref(),
+///     Some("2026-02-26T09:30:00+01:00")
+/// );
+/// assert_eq!(pdf_date_to_xmp("D:2026").as_deref(), Some("2026-01-01T00:00:00"));
+/// assert_eq!(pdf_date_to_xmp("yesterday"), None);
+/// ```
+pub fn pdf_date_to_xmp(date: &str) -> Option<String> {
+    let raw = date.strip_prefix("D:").unwrap_or(date).as_bytes();
+    let field = |start: usize, len: usize, default: &'static str| -> Option<String> {
+        match raw.get(start..start + len) {
+            Some(digits) if digits.iter().all(u8::is_ascii_digit) => {
+                Some(String::from_utf8_lossy(digits).into_owned())
+            },
+            Some(_) => None,
+            None if start >= raw.len() => Some(default.to_string()),
+            None => None,
+        }
+    };
+
+    if raw.len() < 4 {
+        return None;
+    }
+    let year = field(0, 4, "")?;
+    let month = field(4, 2, "01")?;
+    let day = field(6, 2, "01")?;
+    let hour = field(8, 2, "00")?;
+    let minute = field(10, 2, "00")?;
+    let second = field(12, 2, "00")?;
+    let zone = match raw.get(14) {
+        None => String::new(),
+        Some(b'Z') => "Z".to_string(),
+        Some(&sign @ (b'+' | b'-')) => {
+            let hours = field(15, 2, "00")?;
+            let minutes_at = if raw.get(17) == Some(&b'\'') { 18 } else { 17 };
+            let minutes = field(minutes_at, 2, "00")?;
+            format!("{}{}:{}", sign as char, hours, minutes)
+        },
+        Some(_) => return None,
+    };
+    Some(format!("{year}-{month}-{day}T{hour}:{minute}:{second}{zone}"))
+}
+
 /// Generate current timestamp in ISO 8601 format.
 pub fn iso_timestamp() -> String {
     chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()
@@ -413,4 +485,25 @@ mod tests {
         assert!(xml.contains("All rights reserved"));
         assert!(xml.contains("<xmpRights:Marked>True</xmpRights:Marked>"));
     }
+
+    #[test]
+    fn test_xmp_writer_pdfua_part() {
+        let xml = XmpWriter::new(XmpMetadata::new()).pdfua_part(1).build();
+        assert!(xml.contains("xmlns:pdfuaid=\"http://www.aiim.org/pdfua/ns/id/\""));
+        assert!(xml.contains("<pdfuaid:part>1</pdfuaid:part>"));
+
+        let xml = XmpWriter::new(XmpMetadata::new()).build();
+        assert!(!xml.contains("pdfuaid"));
+    }
+
+    #[test]
+    fn test_pdf_date_to_xmp_rejects_malformed_dates() {
+        assert_eq!(pdf_date_to_xmp("D:2026-01-01"), None);
+        assert_eq!(pdf_date_to_xmp("D:20260101120000X"), None);
+        assert_eq!(pdf_date_to_xmp("D:20260101120000-05'3"), None);
+        assert_eq!(
+            pdf_date_to_xmp("20260101120000-05'30'").as_deref(),
+            Some("2026-01-01T12:00:00-05:30")
+        );
+    }
 }
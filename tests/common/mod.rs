@@ -0,0 +1,109 @@
+//! Fixtures shared by the integration tests.
+//!
+//! Each test binary compiles this module separately and uses only part of
+//! it, hence the `dead_code` allowance.
+
+#![allow(dead_code, clippy::same_item_push)]
+
+/// Create a minimal valid RGBA PNG image in memory (alpha channel present)
+pub fn create_test_png_rgba(width: u32, height: u32) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut data = Vec::new();
+    data.extend_from_slice(b"\x89PNG\r\n\x1a\n");
+
+    let mut raw_pixels = Vec::new();
+    for _ in 0..height {
+        raw_pixels.push(0); // None-filter byte per row
+        for _ in 0..width {
+            raw_pixels.extend_from_slice(&[255, 0, 0, 128]); // half-transparent red
+        }
+    }
+
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(&raw_pixels).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    fn write_chunk(out: &mut Vec<u8>, chunk_type: &[u8; 4], chunk_data: &[u8]) {
+        out.extend_from_slice(&(chunk_data.len() as u32).to_be_bytes());
+        out.extend_from_slice(chunk_type);
+        out.extend_from_slice(chunk_data);
+        let mut crc_data = Vec::new();
+        crc_data.extend_from_slice(chunk_type);
+        crc_data.extend_from_slice(chunk_data);
+        out.extend_from_slice(&crc32fast::hash(&crc_data).to_be_bytes());
+    }
+
+    let mut ihdr_data = Vec::new();
+    ihdr_data.extend_from_slice(&width.to_be_bytes());
+    ihdr_data.extend_from_slice(&height.to_be_bytes());
+    ihdr_data.push(8); // bit depth
+    ihdr_data.push(6); // color type RGBA
+    ihdr_data.push(0); // compression
+    ihdr_data.push(0); // filter
+    ihdr_data.push(0); // interlace
+    write_chunk(&mut data, b"IHDR", &ihdr_data);
+    write_chunk(&mut data, b"IDAT", &compressed);
+    write_chunk(&mut data, b"IEND", &[]);
+
+    data
+}
+
+/// Create a minimal valid PNG image in memory
+pub fn create_test_png(width: u32, height: u32) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut data = Vec::new();
+
+    // PNG signature
+    data.extend_from_slice(b"\x89PNG\r\n\x1a\n");
+
+    // Create raw pixel data (RGB)
+    let mut raw_pixels = Vec::new();
+    for _ in 0..height {
+        raw_pixels.push(0); // Filter byte (None)
+        for _ in 0..width {
+            raw_pixels.extend_from_slice(&[255, 0, 0]); // Red pixel
+        }
+    }
+
+    // Compress with zlib
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(&raw_pixels).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    // Helper to write a chunk
+    fn write_chunk(out: &mut Vec<u8>, chunk_type: &[u8; 4], chunk_data: &[u8]) {
+        // Length (big-endian)
+        out.extend_from_slice(&(chunk_data.len() as u32).to_be_bytes());
+        // Type
+        out.extend_from_slice(chunk_type);
+        // Data
+        out.extend_from_slice(chunk_data);
+        // CRC32 (over type + data)
+        let mut crc_data = Vec::new();
+        crc_data.extend_from_slice(chunk_type);
+        crc_data.extend_from_slice(chunk_data);
+        let crc = crc32fast::hash(&crc_data);
+        out.extend_from_slice(&crc.to_be_bytes());
+    }
+
+    // IHDR chunk
+    let mut ihdr_data = Vec::new();
+    ihdr_data.extend_from_slice(&width.to_be_bytes());
+    ihdr_data.extend_from_slice(&height.to_be_bytes());
+    ihdr_data.push(8); // bit depth
+    ihdr_data.push(2); // color type (RGB)
+    ihdr_data.push(0); // compression
+    ihdr_data.push(0); // filter
+    ihdr_data.push(0); // interlace
+    write_chunk(&mut data, b"IHDR", &ihdr_data);
+
+    // IDAT chunk (compressed pixel data)
+    write_chunk(&mut data, b"IDAT", &compressed);
+
+    // IEND chunk
+    write_chunk(&mut data, b"IEND", &[]);
+
+    data
+}
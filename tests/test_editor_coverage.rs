@@ -1610,6 +1610,7 @@ fn test_editor_save_with_compression() {
         garbage_collect: true,
         linearize: false,
         encryption: None,
+        update_mod_date: false,
     };
     editor.save_with_options(&out_path, opts).unwrap();
 
@@ -1633,6 +1634,7 @@ fn test_editor_save_no_compress_no_gc() {
         garbage_collect: false,
         linearize: false,
         encryption: None,
+        update_mod_date: false,
     };
     editor.save_with_options(&out_path, opts).unwrap();
 
@@ -1836,6 +1838,7 @@ fn test_save_to_bytes_with_compress_produces_valid_pdf() {
         linearize: false,
         incremental: false,
         encryption: None,
+        update_mod_date: false,
     };
     let bytes = editor.save_to_bytes_with_options(opts).unwrap();
     assert!(!bytes.is_empty());
@@ -1859,6 +1862,7 @@ fn test_save_to_bytes_with_garbage_collect_produces_valid_pdf() {
         linearize: false,
         incremental: false,
         encryption: None,
+        update_mod_date: false,
     };
     let bytes = editor.save_to_bytes_with_options(opts).unwrap();
     assert!(!bytes.is_empty());
@@ -1917,6 +1921,7 @@ fn test_gc_produces_smaller_or_equal_output_than_no_gc() {
             linearize: false,
             incremental: false,
             encryption: None,
+            update_mod_date: false,
         })
         .unwrap();
 
@@ -1928,6 +1933,7 @@ fn test_gc_produces_smaller_or_equal_output_than_no_gc() {
             linearize: false,
             incremental: false,
             encryption: None,
+            update_mod_date: false,
         })
         .unwrap();
 
@@ -1975,6 +1981,7 @@ fn test_compress_produces_smaller_or_equal_output_for_raw_streams() {
             linearize: false,
             incremental: false,
             encryption: None,
+            update_mod_date: false,
         })
         .unwrap();
 
@@ -1986,6 +1993,7 @@ fn test_compress_produces_smaller_or_equal_output_for_raw_streams() {
             linearize: false,
             incremental: false,
             encryption: None,
+            update_mod_date: false,
         })
         .unwrap();
 
@@ -2015,6 +2023,7 @@ fn test_linearize_true_does_not_panic() {
         linearize: true,
         incremental: false,
         encryption: None,
+        update_mod_date: false,
     };
     let bytes = editor.save_to_bytes_with_options(opts).unwrap();
     assert!(bytes.starts_with(b"%PDF-"));
@@ -0,0 +1,67 @@
+//! `FlowDocument` end-to-end: blocks flow across pages, running headers
+//! and footers are drawn on each page, and the output parses back.
+
+use pdf_oxide::writer::{Artifact, DocumentMetadata, FlowDocument, Table, TableCell};
+use pdf_oxide::PdfDocument;
+
+fn invoice(line_items: usize) -> FlowDocument {
+    let mut rows = vec![vec![
+        TableCell::header("Description"),
+        TableCell::header("Amount"),
+    ]];
+    for i in 0..line_items {
+        rows.push(vec![
+            TableCell::text(format!("Service line {i}")),
+            TableCell::number("10.00"),
+        ]);
+    }
+    FlowDocument::new()
+        .metadata(DocumentMetadata::new().title("Invoice 2041"))
+        .header(Artifact::right("{title}"))
+        .footer(Artifact::center("Page {page} of {pages}"))
+        .section("Invoice 2041")
+        .paragraph("Thank you for your order. Payment is due within 30 days.")
+        .table(Table::new(rows).with_header_row())
+        .heading(2, "Notes")
+        .paragraph("All amounts in EUR.")
+}
+
+#[test]
+fn flow_document_paginates_with_running_header_and_footer() {
+    let bytes = invoice(120).build().unwrap();
+    let raw = String::from_utf8_lossy(&bytes).into_owned();
+    let doc = PdfDocument::from_bytes(bytes).unwrap();
+    let pages = doc.page_count().unwrap();
+    assert!(pages > 2, "120 rows should span several pages, got {pages}");
+
+    for n in 1..=pages {
+        assert!(raw.contains(&format!("(Page {n} of {pages})")), "missing footer on page {n}");
+    }
+    // The table header row is repeated on every page the table touches.
+    assert!(raw.matches("(Description)").count() >= pages - 1);
+
+    let first = doc.extract_text(0).unwrap();
+    assert!(first.contains("Service line 0"), "first page: {first:?}");
+    let last = doc.extract_text(pages - 1).unwrap();
+    assert!(last.contains("All amounts in EUR."), "last page: {last:?}");
+}
+
+#[test]
+fn flow_document_short_content_fits_one_page() {
+    let bytes = invoice(3).build().unwrap();
+    let doc = PdfDocument::from_bytes(bytes).unwrap();
+    assert_eq!(doc.page_count().unwrap(), 1);
+}
+
+#[test]
+fn flow_document_tagged_output_has_structure_tree() {
+    let bytes = invoice(3).tagged_pdf_ua1().build().unwrap();
+    let raw = String::from_utf8_lossy(&bytes);
+    assert!(raw.contains("/StructTreeRoot"));
+    assert!(raw.contains("/S /H1") || raw.contains("/S/H1"));
+    assert!(raw.contains("/S /Table") || raw.contains("/S/Table"));
+    for tag in ["TR", "TH", "TD"] {
+        assert!(raw.contains(&format!("/S /{tag}")), "missing /{tag}");
+    }
+    assert!(raw.contains("/Artifact"));
+}
@@ -7,8 +7,8 @@
 
 use pdf_oxide::geometry::Rect;
 use pdf_oxide::writer::{
-    CheckboxWidget, ChoiceOption, ComboBoxWidget, FormAction, ListBoxWidget, PdfWriter,
-    PushButtonWidget, RadioButtonGroup, TextAlignment, TextFieldWidget,
+    CheckboxWidget, ChoiceOption, ComboBoxWidget, FitMode, FormAction, InputMask, ListBoxWidget,
+    NamedAction, PdfWriter, PushButtonWidget, RadioButtonGroup, TextAlignment, TextFieldWidget,
 };
 
 #[test]
@@ -35,6 +35,9 @@ fn test_create_pdf_with_text_field() {
     assert!(content.contains("/AcroForm"));
     assert!(content.contains("/FT /Tx")); // Text field type
     assert!(content.contains("/T (name)")); // Field name
+
+    // No appearance stream of our own, so the viewer must build one
+    assert!(content.contains("/NeedAppearances true"));
 }
 
 #[test]
@@ -183,6 +186,65 @@ fn test_create_pdf_with_push_button() {
     assert!(content.contains("/T (reset)")); // Field name
 }
 
+#[test]
+fn test_push_button_navigation_actions() {
+    let mut writer = PdfWriter::new();
+    {
+        let mut page = writer.add_page(612.0, 792.0);
+        page.add_push_button(
+            PushButtonWidget::new("next", Rect::new(72.0, 72.0, 80.0, 25.0))
+                .with_caption("Next section")
+                .with_action(FormAction::JavaScript {
+                    script: "this.calculateNow();".to_string(),
+                })
+                .with_next_action(FormAction::GoTo {
+                    page: 2,
+                    fit: Some(FitMode::FitH(Some(792.0))),
+                }),
+        );
+    }
+    {
+        let mut page = writer.add_page(612.0, 792.0);
+        page.add_push_button(
+            PushButtonWidget::new("back", Rect::new(72.0, 72.0, 80.0, 25.0))
+                .with_caption("Back")
+                .with_action(FormAction::Named {
+                    action: NamedAction::PrevPage,
+                }),
+        );
+    }
+    writer.add_page(612.0, 792.0);
+
+    let bytes = writer.finish().expect("Failed to create PDF");
+    let content = String::from_utf8_lossy(&bytes);
+
+    assert!(content.contains("/S /JavaScript"));
+    assert!(content.contains("/Next <<"));
+    assert!(content.contains("/S /GoTo"));
+    assert!(content.contains("/S /Named"));
+    assert!(content.contains("/N /PrevPage"));
+
+    // The GoTo destination points at the third page object, not an index.
+    let kids_start = content.find("/Kids [").unwrap() + "/Kids [".len();
+    let kids = &content[kids_start..kids_start + content[kids_start..].find(']').unwrap()];
+    let third_page = kids.split(" R").nth(2).unwrap().trim();
+    assert!(content.contains(&format!("/D [{} R /FitH 792", third_page)));
+}
+
+#[test]
+fn test_push_button_goto_past_last_page_fails() {
+    let mut writer = PdfWriter::new();
+    {
+        let mut page = writer.add_page(612.0, 792.0);
+        page.add_push_button(
+            PushButtonWidget::new("next", Rect::new(72.0, 72.0, 80.0, 25.0))
+                .with_action(FormAction::GoTo { page: 5, fit: None }),
+        );
+    }
+
+    assert!(writer.finish().is_err());
+}
+
 #[test]
 fn test_create_complete_form() {
     let mut writer = PdfWriter::new();
@@ -283,6 +345,45 @@ fn test_text_field_options() {
     assert!(content.contains("/T (readonly)"));
 }
 
+#[test]
+fn test_comb_field_with_input_mask() {
+    let mut writer = PdfWriter::new();
+    {
+        let mut page = writer.add_page(612.0, 792.0);
+        page.add_text_field(
+            TextFieldWidget::new("zip", Rect::new(72.0, 700.0, 100.0, 20.0))
+                .with_input_mask(InputMask::us_zip())
+                .with_value("94103")
+                .comb(),
+        );
+        page.add_text_field(
+            TextFieldWidget::new("phone", Rect::new(72.0, 660.0, 200.0, 20.0))
+                .with_input_mask(InputMask::us_phone())
+                .comb(),
+        );
+    }
+
+    let bytes = writer.finish().expect("Failed to create PDF");
+    let content = String::from_utf8_lossy(&bytes);
+
+    // Every field carries its own appearance, so viewers must not regenerate it
+    assert!(!content.contains("/NeedAppearances"));
+    assert!(content.contains("/MaxLen 5"));
+    assert!(content.contains("/MaxLen 14"));
+    assert!(content.contains("999-9999"));
+
+    // The comb appearance is an indirect Form XObject with four cell rules
+    let ap_start = content.find("/AP <</N ").unwrap() + "/AP <</N ".len();
+    let ap_ref = &content[ap_start..ap_start + content[ap_start..].find(">>").unwrap()];
+    assert!(ap_ref.ends_with(" 0 R"), "appearance must be indirect: {ap_ref}");
+    assert!(content.contains("/Subtype /Form"));
+    assert_eq!(content.matches(" l S").count(), 4 + 13);
+    assert!(content.contains("(9) Tj"));
+
+    let doc = pdf_oxide::PdfDocument::from_bytes(bytes).unwrap();
+    assert_eq!(doc.page_count().unwrap(), 1);
+}
+
 #[test]
 fn test_multiple_pages_with_forms() {
     let mut writer = PdfWriter::new();
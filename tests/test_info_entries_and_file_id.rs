@@ -0,0 +1,198 @@
+//! Custom `/Info` entries, explicit trailer `/ID`, and ModDate control
+//! round-trip through save + reopen.
+
+use pdf_oxide::document::PdfDocument;
+use pdf_oxide::editor::{DocumentEditor, EditableDocument, SaveOptions};
+use pdf_oxide::extractors::xmp::XmpExtractor;
+use pdf_oxide::writer::{DocumentBuilder, DocumentMetadata, PageSize};
+
+fn make_doc(metadata: DocumentMetadata) -> Vec<u8> {
+    let mut builder = DocumentBuilder::new().metadata(metadata);
+    builder
+        .page(PageSize::Letter)
+        .at(72.0, 720.0)
+        .text("hello")
+        .done();
+    builder.build().unwrap()
+}
+
+#[test]
+fn builder_writes_custom_info_and_file_id() {
+    let bytes = make_doc(
+        DocumentMetadata::new()
+            .title("Invoice")
+            .keywords("billing")
+            .info_entry("Company", "Acme")
+            .file_id(vec![0xAB; 16], vec![0xCD; 16]),
+    );
+
+    let mut editor = DocumentEditor::from_bytes(bytes).unwrap();
+    assert_eq!(editor.info_entry("Company").unwrap().as_deref(), Some("Acme"));
+    assert_eq!(editor.keywords().unwrap().as_deref(), Some("billing"));
+    assert_eq!(editor.file_id(), Some((vec![0xAB; 16], vec![0xCD; 16])));
+}
+
+#[test]
+fn builder_custom_info_replaces_standard_and_repeated_keys() {
+    let bytes = make_doc(
+        DocumentMetadata::new()
+            .title("Draft")
+            .info_entry("Title", "Final")
+            .info_entry("Company", "Acme")
+            .info_entry("Company", "Globex"),
+    );
+
+    let mut editor = DocumentEditor::from_bytes(bytes).unwrap();
+    assert_eq!(editor.info_entry("Title").unwrap().as_deref(), Some("Final"));
+    assert_eq!(editor.info_entry("Company").unwrap().as_deref(), Some("Globex"));
+}
+
+#[test]
+fn tagged_output_keeps_info_and_xmp_in_sync() {
+    let bytes = make_doc(
+        DocumentMetadata::new()
+            .title("Quarterly report")
+            .author("Zoë Müller")
+            .subject("Finance")
+            .keywords("q3, revenue")
+            .creator("Report Generator")
+            .producer("Acme PDF 2.1")
+            .creation_date("D:20260101120000Z")
+            .mod_date("D:20260226093000+01'00'")
+            .info_entry("Keywords", "q3, revenue, final")
+            .tagged_pdf_ua1()
+            .language("en-US"),
+    );
+
+    let mut editor = DocumentEditor::from_bytes(bytes.clone()).unwrap();
+    let doc = PdfDocument::from_bytes(bytes).unwrap();
+    let xmp = XmpExtractor::extract(&doc)
+        .unwrap()
+        .expect("tagged output has XMP");
+
+    assert_eq!(xmp.dc_title, editor.title().unwrap());
+    assert_eq!(xmp.dc_creator, editor.author().unwrap().into_iter().collect::<Vec<_>>());
+    assert_eq!(xmp.dc_description, editor.subject().unwrap());
+    assert_eq!(xmp.pdf_keywords.as_deref(), Some("q3, revenue, final"));
+    assert_eq!(xmp.pdf_keywords, editor.keywords().unwrap());
+    assert_eq!(xmp.pdf_producer, editor.producer().unwrap());
+    assert_eq!(editor.creation_date().unwrap().as_deref(), Some("D:20260101120000Z"));
+    assert_eq!(xmp.xmp_create_date.as_deref(), Some("2026-01-01T12:00:00Z"));
+    assert_eq!(editor.mod_date().unwrap().as_deref(), Some("D:20260226093000+01'00'"));
+    assert_eq!(xmp.xmp_modify_date.as_deref(), Some("2026-02-26T09:30:00+01:00"));
+}
+
+#[test]
+fn builder_omits_file_id_by_default() {
+    let bytes = make_doc(DocumentMetadata::new());
+    let editor = DocumentEditor::from_bytes(bytes).unwrap();
+    assert_eq!(editor.file_id(), None);
+}
+
+#[test]
+fn custom_info_entry_round_trips_through_save() {
+    let mut editor = DocumentEditor::from_bytes(make_doc(DocumentMetadata::new())).unwrap();
+    editor.set_info_entry("SourceSystem", "ERP-7");
+    editor.set_info_entry("Title", "Routed to the standard field");
+
+    let saved = editor.save_to_bytes().unwrap();
+    let mut reopened = DocumentEditor::from_bytes(saved).unwrap();
+    let info = reopened.get_info().unwrap();
+    assert_eq!(info.custom.get("SourceSystem").map(String::as_str), Some("ERP-7"));
+    assert_eq!(info.title.as_deref(), Some("Routed to the standard field"));
+    assert!(!info.custom.contains_key("Title"));
+}
+
+#[test]
+fn remove_info_entry_drops_key() {
+    let bytes = make_doc(DocumentMetadata::new().info_entry("Draft", "yes"));
+    let mut editor = DocumentEditor::from_bytes(bytes).unwrap();
+    assert_eq!(editor.remove_info_entry("Draft").as_deref(), Some("yes"));
+
+    let saved = editor.save_to_bytes().unwrap();
+    let mut reopened = DocumentEditor::from_bytes(saved).unwrap();
+    assert_eq!(reopened.info_entry("Draft").unwrap(), None);
+}
+
+#[test]
+fn unmodified_info_survives_full_rewrite() {
+    let bytes = make_doc(
+        DocumentMetadata::new()
+            .title("Keep me")
+            .info_entry("Company", "Acme"),
+    );
+    let mut editor = DocumentEditor::from_bytes(bytes).unwrap();
+
+    let saved = editor.save_to_bytes().unwrap();
+    let mut reopened = DocumentEditor::from_bytes(saved).unwrap();
+    assert_eq!(reopened.title().unwrap().as_deref(), Some("Keep me"));
+    assert_eq!(reopened.info_entry("Company").unwrap().as_deref(), Some("Acme"));
+}
+
+#[test]
+fn file_id_is_preserved_across_saves() {
+    let bytes = make_doc(
+        DocumentMetadata::new().file_id(b"permanent-id-123".to_vec(), b"changing".to_vec()),
+    );
+    let mut editor = DocumentEditor::from_bytes(bytes).unwrap();
+    editor.set_title("edited");
+
+    let saved = editor.save_to_bytes().unwrap();
+    let reopened = DocumentEditor::from_bytes(saved).unwrap();
+    assert_eq!(reopened.file_id(), Some((b"permanent-id-123".to_vec(), b"changing".to_vec())));
+}
+
+#[test]
+fn explicit_file_id_overrides_source() {
+    let bytes = make_doc(DocumentMetadata::new().file_id(vec![1; 16], vec![2; 16]));
+    let mut editor = DocumentEditor::from_bytes(bytes).unwrap();
+    editor.set_file_id(vec![1; 16], vec![3; 16]);
+
+    let saved = editor.save_to_bytes().unwrap();
+    let reopened = DocumentEditor::from_bytes(saved).unwrap();
+    assert_eq!(reopened.file_id(), Some((vec![1; 16], vec![3; 16])));
+}
+
+#[test]
+fn incremental_update_keeps_info_and_file_id() {
+    let bytes = make_doc(
+        DocumentMetadata::new()
+            .title("Original")
+            .file_id(vec![7; 16], vec![8; 16]),
+    );
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("src.pdf");
+    let out = dir.path().join("out.pdf");
+    std::fs::write(&src, bytes).unwrap();
+
+    let mut editor = DocumentEditor::open(&src).unwrap();
+    editor
+        .save_with_options(&out, SaveOptions::incremental())
+        .unwrap();
+
+    let mut reopened = DocumentEditor::open(&out).unwrap();
+    assert_eq!(reopened.title().unwrap().as_deref(), Some("Original"));
+    assert_eq!(reopened.file_id(), Some((vec![7; 16], vec![8; 16])));
+}
+
+#[test]
+fn mod_date_left_alone_unless_requested() {
+    let bytes = make_doc(DocumentMetadata::new().mod_date("D:20200101000000Z"));
+
+    let mut editor = DocumentEditor::from_bytes(bytes.clone()).unwrap();
+    editor.set_title("edited");
+    let saved = editor.save_to_bytes().unwrap();
+    let mut reopened = DocumentEditor::from_bytes(saved).unwrap();
+    assert_eq!(reopened.mod_date().unwrap().as_deref(), Some("D:20200101000000Z"));
+
+    let mut editor = DocumentEditor::from_bytes(bytes).unwrap();
+    let options = SaveOptions {
+        update_mod_date: true,
+        ..SaveOptions::full_rewrite()
+    };
+    let saved = editor.save_to_bytes_with_options(options).unwrap();
+    let mut reopened = DocumentEditor::from_bytes(saved).unwrap();
+    let mod_date = reopened.mod_date().unwrap().unwrap();
+    assert!(mod_date.starts_with("D:"));
+    assert_ne!(mod_date, "D:20200101000000Z");
+}
@@ -0,0 +1,139 @@
+//! Identical images and fonts used on several pages are written once and
+//! shared through the page resource dictionaries.
+
+mod common;
+
+use common::create_test_png;
+use pdf_oxide::editor::{DocumentEditor, EditableDocument};
+use pdf_oxide::writer::{DocumentBuilder, EmbeddedFont, PageSize};
+
+fn text_pages(count: usize) -> Vec<u8> {
+    let mut builder = DocumentBuilder::new();
+    for i in 0..count {
+        builder
+            .page(PageSize::Letter)
+            .at(72.0, 720.0)
+            .text(&format!("Page {}", i + 1))
+            .done();
+    }
+    builder.build().unwrap()
+}
+
+fn count(haystack: &[u8], needle: &[u8]) -> usize {
+    haystack
+        .windows(needle.len())
+        .filter(|w| *w == needle)
+        .count()
+}
+
+#[test]
+fn same_image_on_every_page_is_written_once() {
+    let png = create_test_png(8, 8);
+    let mut editor = DocumentEditor::from_bytes(text_pages(5)).unwrap();
+    for page in 0..5 {
+        editor
+            .add_image_bytes_to_page(page, &png, 400.0, 72.0, 100.0, 40.0)
+            .unwrap();
+    }
+
+    let bytes = editor.save_to_bytes().unwrap();
+    assert_eq!(count(&bytes, b"/Subtype /Image"), 1);
+
+    let mut reopened = DocumentEditor::from_bytes(bytes).unwrap();
+    assert_eq!(reopened.page_count().unwrap(), 5);
+}
+
+#[test]
+fn distinct_images_are_kept_apart() {
+    let mut editor = DocumentEditor::from_bytes(text_pages(2)).unwrap();
+    editor
+        .add_image_bytes_to_page(0, &create_test_png(8, 8), 400.0, 72.0, 100.0, 40.0)
+        .unwrap();
+    editor
+        .add_image_bytes_to_page(1, &create_test_png(9, 8), 400.0, 72.0, 100.0, 40.0)
+        .unwrap();
+
+    let bytes = editor.save_to_bytes().unwrap();
+    assert_eq!(count(&bytes, b"/Subtype /Image"), 2);
+}
+
+#[test]
+fn merged_documents_share_identical_fonts() {
+    let font = EmbeddedFont::from_file("tests/fixtures/fonts/DejaVuSans.ttf").unwrap();
+    let mut builder = DocumentBuilder::new().register_embedded_font("DejaVu", font);
+    for i in 0..3 {
+        builder
+            .a4_page()
+            .font("DejaVu", 12.0)
+            .at(72.0, 700.0)
+            .text(&format!("Привет {i}"))
+            .done();
+    }
+    let appendix = builder.build().unwrap();
+
+    let mut editor = DocumentEditor::from_bytes(text_pages(1)).unwrap();
+    editor.merge_from_bytes(&appendix).unwrap();
+    editor.merge_from_bytes(&appendix).unwrap();
+
+    let bytes = editor.save_to_bytes().unwrap();
+    assert_eq!(count(&bytes, b"/FontFile2"), 1);
+
+    let mut reopened = DocumentEditor::from_bytes(bytes).unwrap();
+    assert_eq!(reopened.page_count().unwrap(), 7);
+}
+
+/// Assemble a classic-xref PDF from object bodies numbered from 1.
+fn raw_pdf(objects: &[&[u8]]) -> Vec<u8> {
+    let mut out = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (i, body) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+        out.extend_from_slice(body);
+        out.extend_from_slice(b"\nendobj\n");
+    }
+    let xref = out.len();
+    out.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in &offsets {
+        out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    out.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        )
+        .as_bytes(),
+    );
+    out
+}
+
+const CATALOG: &[u8] = b"<< /Type /Catalog /Pages 2 0 R >>";
+const PAGES: &[u8] = b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>";
+
+#[test]
+fn image_color_space_children_are_not_duplicated() {
+    let source = raw_pdf(&[
+        CATALOG,
+        PAGES,
+        b"<< /Type /Page /MediaBox [0 0 200 200] /Resources << /XObject << /Im0 4 0 R >> >> \
+              /Contents 6 0 R >>",
+        b"<< /Type /XObject /Subtype /Image /Width 1 /Height 1 /BitsPerComponent 8 \
+              /ColorSpace 5 0 R /Length 1 >>\nstream\n\x01\nendstream",
+        b"[/Indexed /DeviceRGB 1 <FF000000FF00>]",
+        b"<< /Length 27 >>\nstream\nq 10 0 0 10 0 0 cm /Im0 Do Q\nendstream",
+    ]);
+
+    let mut editor = DocumentEditor::from_bytes(text_pages(1)).unwrap();
+    editor.merge_from_bytes(&source).unwrap();
+    editor.merge_from_bytes(&source).unwrap();
+
+    let bytes = editor.save_to_bytes().unwrap();
+    assert_eq!(count(&bytes, b"/Subtype /Image"), 1);
+    assert_eq!(count(&bytes, b"/Indexed"), 1);
+
+    let mut reopened = DocumentEditor::from_bytes(bytes).unwrap();
+    assert_eq!(reopened.page_count().unwrap(), 3);
+}
@@ -0,0 +1,136 @@
+//! Non-ASCII field names, values, options and metadata survive a write +
+//! read cycle through both the writer and the editor.
+
+use pdf_oxide::document::PdfDocument;
+use pdf_oxide::editor::{DocumentEditor, EditableDocument, FormFieldValue};
+use pdf_oxide::extractors::forms::{FieldValue, FormExtractor};
+use pdf_oxide::geometry::Rect;
+use pdf_oxide::writer::{
+    ComboBoxWidget, DocumentBuilder, DocumentMetadata, PageSize, PdfWriter, TextFieldWidget,
+};
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+#[test]
+fn writer_field_names_and_options_round_trip() {
+    let mut writer = PdfWriter::new();
+    {
+        let mut page = writer.add_page(612.0, 792.0);
+        page.add_text_field(
+            TextFieldWidget::new("Prénom", Rect::new(72.0, 700.0, 200.0, 20.0))
+                .with_value("Zoë (née Müller)")
+                .with_tooltip("名前"),
+        );
+        page.add_combo_box(
+            ComboBoxWidget::new("Город", Rect::new(72.0, 650.0, 200.0, 20.0))
+                .with_options(vec!["Москва", "Zürich", "東京"])
+                .with_value("東京"),
+        );
+    }
+    let bytes = writer.finish().unwrap();
+
+    // Latin-1 names are PDFDocEncoded, everything else is UTF-16BE with BOM
+    assert!(contains(&bytes, b"/T <5072E96E6F6D>"));
+    assert!(contains(&bytes, b"/T <FEFF0413043E0440043E0434>"));
+    assert!(contains(&bytes, b"<FEFF67714EAC>"));
+
+    let doc = PdfDocument::from_bytes(bytes).unwrap();
+    let fields = FormExtractor::extract_fields(&doc).unwrap();
+    let first = fields.iter().find(|f| f.name == "Prénom").unwrap();
+    assert_eq!(first.value, FieldValue::Text("Zoë (née Müller)".to_string()));
+    assert_eq!(first.tooltip.as_deref(), Some("名前"));
+    let city = fields.iter().find(|f| f.name == "Город").unwrap();
+    assert_eq!(city.value, FieldValue::Text("東京".to_string()));
+}
+
+#[test]
+fn editor_field_values_round_trip() {
+    let mut editor = DocumentEditor::open("tests/fixtures/simple.pdf").unwrap();
+    editor
+        .add_form_field(0, TextFieldWidget::new("Straße", Rect::new(100.0, 700.0, 200.0, 20.0)))
+        .unwrap();
+    editor
+        .set_form_field_value("Straße", FormFieldValue::Text("Ελληνικά (1)".to_string()))
+        .unwrap();
+
+    let saved = editor.save_to_bytes().unwrap();
+    assert!(!contains(&saved, "Ελληνικά".as_bytes()));
+
+    let doc = PdfDocument::from_bytes(saved).unwrap();
+    let fields = FormExtractor::extract_fields(&doc).unwrap();
+    let street = fields.iter().find(|f| f.name == "Straße").unwrap();
+    assert_eq!(street.value, FieldValue::Text("Ελληνικά (1)".to_string()));
+}
+
+#[test]
+fn editor_metadata_round_trips() {
+    let mut builder = DocumentBuilder::new().metadata(DocumentMetadata::new().title("Übersicht"));
+    builder
+        .page(PageSize::Letter)
+        .at(72.0, 720.0)
+        .text("hello")
+        .done();
+    let mut editor = DocumentEditor::from_bytes(builder.build().unwrap()).unwrap();
+
+    // Reading back an existing PDFDocEncoded title
+    assert_eq!(editor.get_info().unwrap().title.as_deref(), Some("Übersicht"));
+
+    editor.set_info_entry("Title", "Отчёт 2026");
+    editor.set_info_entry("Abteilung", "Zürich – Forschung");
+    editor.set_info_entry("Standort", "Zürich");
+    let saved = editor.save_to_bytes().unwrap();
+    assert!(!contains(&saved, "Отчёт".as_bytes()));
+
+    let mut reopened = DocumentEditor::from_bytes(saved).unwrap();
+    let info = reopened.get_info().unwrap();
+    assert_eq!(info.title.as_deref(), Some("Отчёт 2026"));
+    assert_eq!(info.custom.get("Abteilung").map(String::as_str), Some("Zürich – Forschung"));
+    assert_eq!(info.custom.get("Standort").map(String::as_str), Some("Zürich"));
+}
+
+#[test]
+fn info_keys_with_delimiters_round_trip() {
+    let mut builder = DocumentBuilder::new();
+    builder
+        .page(PageSize::Letter)
+        .at(72.0, 720.0)
+        .text("hello")
+        .done();
+    let mut editor = DocumentEditor::from_bytes(builder.build().unwrap()).unwrap();
+    editor.set_info_entry("Approved (QA)", "yes");
+    editor.set_info_entry("Cost <EUR>", "12");
+    editor.set_info_entry("Path/[x]{y}%", "z");
+    let saved = editor.save_to_bytes().unwrap();
+    assert!(contains(&saved, b"/Approved#20#28QA#29"));
+
+    let mut reopened = DocumentEditor::from_bytes(saved).unwrap();
+    assert_eq!(reopened.info_entry("Approved (QA)").unwrap().as_deref(), Some("yes"));
+    assert_eq!(reopened.info_entry("Cost <EUR>").unwrap().as_deref(), Some("12"));
+    assert_eq!(reopened.info_entry("Path/[x]{y}%").unwrap().as_deref(), Some("z"));
+}
+
+#[test]
+fn cjk_info_key_and_field_name_round_trip() {
+    let mut editor = DocumentEditor::open("tests/fixtures/simple.pdf").unwrap();
+    editor
+        .add_form_field(0, TextFieldWidget::new("氏名", Rect::new(100.0, 700.0, 200.0, 20.0)))
+        .unwrap();
+    editor
+        .set_form_field_value("氏名", FormFieldValue::Text("山田".to_string()))
+        .unwrap();
+    editor.set_info_entry("部署", "営業");
+    editor.set_info_entry("Größe日", "x");
+    let saved = editor.save_to_bytes().unwrap();
+    assert!(contains(&saved, b"/#E9#83#A8#E7#BD#B2"));
+
+    let mut reopened = DocumentEditor::from_bytes(saved.clone()).unwrap();
+    assert_eq!(reopened.info_entry("部署").unwrap().as_deref(), Some("営業"));
+    assert_eq!(reopened.info_entry("Größe日").unwrap().as_deref(), Some("x"));
+
+    let doc = PdfDocument::from_bytes(saved).unwrap();
+    let fields = FormExtractor::extract_fields(&doc).unwrap();
+    let name = fields.iter().find(|f| f.name == "氏名").unwrap();
+    assert_eq!(name.value, FieldValue::Text("山田".to_string()));
+}
@@ -0,0 +1,29 @@
+//! SVG export of pages loaded from real PDF bytes.
+
+mod common;
+
+use common::create_test_png;
+use pdf_oxide::editor::DocumentEditor;
+use pdf_oxide::geometry::Rect;
+use pdf_oxide::writer::{DocumentBuilder, PageSize};
+
+#[test]
+fn exported_page_contains_text_and_image() {
+    let mut builder = DocumentBuilder::new();
+    builder
+        .page(PageSize::Letter)
+        .at(72.0, 720.0)
+        .text("Quarterly summary")
+        .image_from_bytes(&create_test_png(8, 8), Rect::new(72.0, 500.0, 100.0, 40.0))
+        .unwrap()
+        .done();
+    let mut editor = DocumentEditor::from_bytes(builder.build().unwrap()).unwrap();
+
+    let svg = editor.get_page(0).unwrap().to_svg();
+    assert!(svg.starts_with("<?xml"));
+    assert!(svg.contains("xmlns:xlink=\"http://www.w3.org/1999/xlink\""));
+    assert!(svg.contains("Quarterly summary"), "{svg}");
+    assert!(svg.contains("<image transform=\"matrix(100 0 0 -40 72 540)\""), "{svg}");
+    assert!(svg.contains("xlink:href=\"data:image/png;base64,"), "{svg}");
+    assert!(svg.trim_end().ends_with("</svg>"));
+}